@@ -0,0 +1,7 @@
+//! Compiles `proto/events.proto` into the `iroha::event::grpc::pb` module consumed by the
+//! gRPC event-streaming transport.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tonic_build::compile_protos("proto/events.proto")?;
+    Ok(())
+}
@@ -0,0 +1,142 @@
+//! A bounded, server-side ring buffer of recently emitted events, keyed by a monotonic
+//! sequence id, so a reconnecting [`Consumer`](super::Consumer) can replay exactly the
+//! backlog it missed while disconnected instead of silently resuming from "now".
+
+use async_std::sync::{Arc, Mutex};
+use iroha_data_model::events::prelude::*;
+use std::collections::VecDeque;
+
+use super::BlockHeight;
+
+/// Monotonic identifier assigned to every event as it is emitted, independent of which
+/// block produced it. This is the cursor a [`SubscriptionRequest`](super::SubscriptionRequest)
+/// resumes a subscription from.
+pub type EventSequence = u64;
+
+/// Shared handle to the server-side event backlog. Cheaply cloned: the event-publishing
+/// loop and every [`Consumer`](super::Consumer) hold independent handles to the same
+/// underlying ring buffer.
+#[derive(Debug, Clone)]
+pub struct EventBacklog(Arc<Mutex<Inner>>);
+
+#[derive(Debug)]
+struct Inner {
+    capacity: usize,
+    entries: VecDeque<(EventSequence, BlockHeight, Event)>,
+    next_sequence: EventSequence,
+}
+
+impl EventBacklog {
+    /// Constructs an empty backlog retaining at most `capacity` of the most recently
+    /// emitted events.
+    pub fn new(capacity: usize) -> Self {
+        Self(Arc::new(Mutex::new(Inner {
+            capacity,
+            entries: VecDeque::new(),
+            next_sequence: 0,
+        })))
+    }
+
+    /// Records `event`, originating from the block at `height`, assigning and returning
+    /// its sequence id and evicting the oldest retained entry once the backlog is at
+    /// capacity. `height` is supplied by the caller (the event-publishing loop already
+    /// knows which block it's committing) rather than read off `event` itself, since
+    /// `Event` has no such accessor.
+    pub async fn record(&self, height: BlockHeight, event: Event) -> EventSequence {
+        let mut inner = self.0.lock().await;
+        let sequence = inner.next_sequence;
+        inner.next_sequence += 1;
+        if inner.capacity == 0 {
+            return sequence;
+        }
+        if inner.entries.len() == inner.capacity {
+            inner.entries.pop_front();
+        }
+        inner.entries.push_back((sequence, height, event));
+        sequence
+    }
+
+    /// Returns every buffered event (with the block height it originated from) with a
+    /// sequence id strictly greater than `cursor`, in order, so a resuming subscriber can
+    /// drain exactly the gap it missed with no duplicates or gaps at the boundary before
+    /// transitioning into the live stream. Returns `None` if `cursor` already fell outside
+    /// the retained window, meaning the gap can no longer be replayed and the subscription
+    /// must be rejected rather than silently skip events.
+    pub async fn replay_since(
+        &self,
+        cursor: EventSequence,
+    ) -> Option<Vec<(EventSequence, BlockHeight, Event)>> {
+        let inner = self.0.lock().await;
+        let oldest = inner.entries.front().map(|(sequence, ..)| *sequence);
+        if cursor_out_of_window(cursor, oldest, inner.next_sequence) {
+            return None;
+        }
+        Some(
+            inner
+                .entries
+                .iter()
+                .filter(|(sequence, ..)| *sequence > cursor)
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+/// Whether a resume `cursor` has already fallen outside the retained backlog window,
+/// given the oldest sequence id still retained (`None` if the backlog is currently
+/// empty) and the next sequence id that would be assigned.
+///
+/// Split out from [`EventBacklog::replay_since`] so this boundary arithmetic can be
+/// unit-tested without needing a live `Event` to seed the backlog with.
+///
+/// `cursor` is client-supplied, so a resume request for `u64::MAX` must not overflow;
+/// `commitment.rs`'s `is_satisfied_at` guards its height arithmetic against overflow for
+/// the same reason (client-influenced arithmetic on a `u64`).
+fn cursor_out_of_window(
+    cursor: EventSequence,
+    oldest: Option<EventSequence>,
+    next_sequence: EventSequence,
+) -> bool {
+    if let Some(oldest) = oldest {
+        cursor.saturating_add(1) < oldest
+    } else {
+        // Backlog is empty but events have already been emitted and evicted past `cursor`.
+        cursor < next_sequence.saturating_sub(1) && next_sequence > 0
+    }
+}
+
+#[cfg(test)]
+mod cursor_out_of_window_tests {
+    use super::*;
+
+    #[test]
+    fn in_window_when_cursor_immediately_precedes_the_oldest_retained_entry() {
+        assert!(!cursor_out_of_window(4, Some(5), 10));
+    }
+
+    #[test]
+    fn out_of_window_when_cursor_lags_behind_the_oldest_retained_entry() {
+        assert!(cursor_out_of_window(3, Some(5), 10));
+    }
+
+    #[test]
+    fn a_maximal_cursor_does_not_overflow_against_a_nonempty_backlog() {
+        // Regression test: `cursor + 1` used to overflow and panic here.
+        assert!(!cursor_out_of_window(EventSequence::MAX, Some(5), 10));
+    }
+
+    #[test]
+    fn a_fresh_empty_backlog_accepts_any_cursor() {
+        assert!(!cursor_out_of_window(0, None, 0));
+        assert!(!cursor_out_of_window(EventSequence::MAX, None, 0));
+    }
+
+    #[test]
+    fn an_emptied_backlog_still_rejects_a_cursor_fallen_out_of_the_window() {
+        // Every entry has since been evicted; sequences 0..=9 were emitted (next_sequence
+        // is 10), so only a cursor already caught up to the last one (9) is satisfiable.
+        assert!(cursor_out_of_window(3, None, 10));
+        assert!(cursor_out_of_window(8, None, 10));
+        assert!(!cursor_out_of_window(9, None, 10));
+    }
+}
@@ -0,0 +1,107 @@
+//! Depth-based release rule backing commitment-gated event subscriptions.
+//!
+//! A [`Consumer`](super::Consumer) subscribed at anything beyond
+//! [`CommitmentLevel::Processed`] must not release an event until the block it
+//! originated from has reached the requested depth, so integrators don't act on data
+//! that could still be rolled back. Release is driven by block-commit notifications
+//! delivered on a [`BlockHeightReceiver`], not by a fixed time delay.
+
+use async_std::sync::{Receiver, Sender};
+
+/// Chain block height, as reported by block-commit notifications.
+pub type BlockHeight = u64;
+/// Sender half of the block-commit notification channel consumed by a
+/// [`Consumer`](super::Consumer).
+pub type BlockHeightSender = Sender<BlockHeight>;
+/// Receiver half of the block-commit notification channel consumed by a
+/// [`Consumer`](super::Consumer).
+pub type BlockHeightReceiver = Receiver<BlockHeight>;
+
+/// Requested finality guarantee for an event subscription, carried by
+/// [`SubscriptionRequest`](super::SubscriptionRequest).
+///
+/// Declared here rather than reused from `iroha_data_model` because extending the
+/// wire-level subscription request to carry it is a paired client/data-model change
+/// this crate alone can't make; [`super::SubscriptionRequest`] documents the same
+/// limitation for the request type as a whole.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+pub enum CommitmentLevel {
+    /// Release as soon as the event is seen, with no finality guarantee. Matches the
+    /// pre-chunk0-4 behaviour, so it's what an older client's request defaults to.
+    Processed,
+    /// Release once the originating block has one confirmation on top of it.
+    Committed,
+    /// Release once the originating block has two confirmations on top of it.
+    Finalized,
+}
+
+impl Default for CommitmentLevel {
+    fn default() -> Self {
+        Self::Processed
+    }
+}
+
+/// Depth-based release rule for a [`CommitmentLevel`].
+pub(super) trait CommitmentLevelExt {
+    /// Number of additional blocks that must be stacked on top of an event's originating
+    /// block before this level considers it safe to release.
+    fn required_depth(self) -> BlockHeight;
+
+    /// Whether an event originating at `event_height` may be released given the chain has
+    /// advanced to `chain_height`.
+    fn is_satisfied_at(self, event_height: BlockHeight, chain_height: BlockHeight) -> bool;
+}
+
+impl CommitmentLevelExt for CommitmentLevel {
+    fn required_depth(self) -> BlockHeight {
+        match self {
+            CommitmentLevel::Processed => 0,
+            CommitmentLevel::Committed => 1,
+            CommitmentLevel::Finalized => 2,
+        }
+    }
+
+    fn is_satisfied_at(self, event_height: BlockHeight, chain_height: BlockHeight) -> bool {
+        // `Processed` releases unconditionally, matching the pre-chunk0-4 behaviour: an
+        // event's own commit notification isn't guaranteed to have been observed yet (see
+        // `Consumer::consume`/`release_buffered`), so gating even `Processed` on
+        // `chain_height` could stall a subscriber on a quiet chain with no further blocks.
+        if matches!(self, CommitmentLevel::Processed) {
+            return true;
+        }
+        // A depth that would overflow `BlockHeight` can never be reached.
+        event_height
+            .checked_add(self.required_depth())
+            .map_or(false, |needed| chain_height >= needed)
+    }
+}
+
+#[cfg(test)]
+mod is_satisfied_at_tests {
+    use super::*;
+
+    #[test]
+    fn processed_releases_unconditionally_regardless_of_chain_height() {
+        assert!(CommitmentLevel::Processed.is_satisfied_at(100, 0));
+        assert!(CommitmentLevel::Processed.is_satisfied_at(100, 100));
+    }
+
+    #[test]
+    fn committed_requires_one_block_stacked_on_top() {
+        assert!(!CommitmentLevel::Committed.is_satisfied_at(10, 10));
+        assert!(CommitmentLevel::Committed.is_satisfied_at(10, 11));
+    }
+
+    #[test]
+    fn finalized_requires_two_blocks_stacked_on_top() {
+        assert!(!CommitmentLevel::Finalized.is_satisfied_at(10, 11));
+        assert!(CommitmentLevel::Finalized.is_satisfied_at(10, 12));
+    }
+
+    #[test]
+    fn an_event_at_the_maximal_height_does_not_overflow_the_depth_check() {
+        // Regression test: `event_height + required_depth()` must not panic even at the
+        // top of the `BlockHeight` range.
+        assert!(!CommitmentLevel::Committed.is_satisfied_at(BlockHeight::MAX, BlockHeight::MAX));
+    }
+}
@@ -0,0 +1,304 @@
+//! gRPC streaming transport for the event [`Consumer`](super::Consumer).
+//!
+//! The RPC (`proto/events.proto`'s `EventStream::Subscribe`) is a protobuf-defined,
+//! bidirectional-streaming call: the client registers an [`EventFilter`] (and, per
+//! chunk0-4/chunk0-5, a [`CommitmentLevel`] and optional resume cursor) once via a
+//! [`pb::Subscription`] message, then receives a continuous stream of [`pb::EventFrame`]s
+//! — length-prefixed by gRPC's own HTTP/2 framing, not something this module implements
+//! itself. Unlike the WebSocket transport, which waits for an explicit receipt after
+//! every single message, the client instead tops up a flow-control credit window by
+//! sending [`pb::Credit`] messages on the same stream, so a slow subscriber stalls its own
+//! stream instead of the shared event-publishing loop.
+//!
+//! [`GrpcStream`]/[`GrpcSubscriptionRequest`] are the bridge between the generated
+//! [`pb::event_stream_server::EventStream`] service (real network I/O) and the
+//! transport-agnostic [`Consumer`](super::Consumer), exactly as [`WebSocketStream`] is for
+//! the WebSocket transport. [`EventStreamService::subscribe`] drives registration, credit
+//! bridging and backlog replay for real over the network, polling
+//! [`Consumer::release_buffered`](super::Consumer::release_buffered) in a background task
+//! until the backlog has drained, then hands the surviving `Consumer` to a
+//! [`ConsumerRegistry`] — unlike the WebSocket transport, whose `Consumer` stays alive for
+//! free inside the task blocked reading that connection, nothing else would otherwise
+//! retain a gRPC subscriber's `Consumer` once `subscribe` returns. Forwarding *new* live
+//! events still requires the node-wide publish loop calling
+//! [`ConsumerRegistry::consume`] once per committed event, the same loop the WebSocket
+//! entrypoint feeds into; that loop itself lives outside this crate's event module.
+
+use async_std::sync::{Arc, Mutex, Receiver};
+use futures::{channel::mpsc as futures_mpsc, SinkExt, StreamExt};
+use iroha_data_model::events::prelude::*;
+use iroha_version::prelude::*;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status, Streaming};
+
+use super::{EventSequence, TIMEOUT};
+
+/// Generated from `proto/events.proto` by `build.rs` via `tonic_build::compile_protos`.
+pub mod pb {
+    tonic::include_proto!("iroha.event");
+}
+
+/// Number of in-flight events a subscriber may be sent before it must grant more credit.
+const INITIAL_CREDITS: u32 = 64;
+
+/// How often the background task spawned by [`EventStreamService::subscribe`] polls a
+/// registered `Consumer` for newly-committed backlog it can now release.
+const BACKLOG_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// The decoded, transport-agnostic form of a [`pb::Subscription`] registration frame.
+#[derive(Debug)]
+pub struct GrpcSubscriptionRequest {
+    /// The filter matching events the client wants delivered.
+    pub filter: EventFilter,
+    /// The commitment level events must reach before being released to this subscriber.
+    pub commitment: CommitmentLevel,
+    /// The sequence id of the last event this client already received, if resuming.
+    pub cursor: Option<EventSequence>,
+    /// Credit window granted at registration time; see [`GrpcStream`].
+    pub credits: u32,
+}
+
+impl GrpcSubscriptionRequest {
+    /// Decodes a [`pb::Subscription`] off the wire. The filter travels as
+    /// JSON-encoded bytes (it isn't part of the versioned `Event` wire format already
+    /// handled by [`iroha_version`]), so a malformed payload is reported as an invalid
+    /// request rather than panicking.
+    fn decode(sub: pb::Subscription) -> Result<Self, Status> {
+        let filter = serde_json::from_slice(&sub.filter)
+            .map_err(|e| Status::invalid_argument(format!("malformed filter: {e}")))?;
+        let commitment = match pb::CommitmentLevel::try_from(sub.commitment) {
+            Ok(pb::CommitmentLevel::Processed) => CommitmentLevel::Processed,
+            Ok(pb::CommitmentLevel::Committed) => CommitmentLevel::Committed,
+            Ok(pb::CommitmentLevel::Finalized) => CommitmentLevel::Finalized,
+            Err(_) => return Err(Status::invalid_argument("unknown commitment level")),
+        };
+        Ok(Self {
+            filter,
+            commitment,
+            cursor: sub.cursor,
+            credits: INITIAL_CREDITS,
+        })
+    }
+}
+
+/// Server-side handle for a single gRPC event-streaming subscriber.
+///
+/// `registration` yields the client's [`GrpcSubscriptionRequest`] exactly once, `events`
+/// is where matching events are pushed as [`pb::EventFrame`]s, and `credits` is
+/// replenished by [`pb::Credit`] messages the client sends on the same stream.
+#[derive(Debug)]
+pub struct GrpcStream {
+    registration: Receiver<GrpcSubscriptionRequest>,
+    events: mpsc::Sender<Result<pb::EventFrame, Status>>,
+    credits: futures_mpsc::Receiver<u32>,
+    available: u32,
+}
+
+impl GrpcStream {
+    /// Constructs a `GrpcStream` from its transport-level channel halves. Called by
+    /// [`EventStreamService::subscribe`] once it has split the inbound
+    /// [`pb::ClientMessage`] stream into its registration and credit halves.
+    pub fn new(
+        registration: Receiver<GrpcSubscriptionRequest>,
+        events: mpsc::Sender<Result<pb::EventFrame, Status>>,
+        credits: futures_mpsc::Receiver<u32>,
+    ) -> Self {
+        Self {
+            registration,
+            events,
+            credits,
+            available: 0,
+        }
+    }
+
+    /// Reads the client's registration frame and returns it, seeding the credit window
+    /// it granted.
+    pub async fn recv_subscription(&mut self) -> Result<GrpcSubscriptionRequest, String> {
+        let request = async_std::future::timeout(TIMEOUT, self.registration.recv())
+            .await
+            .map_err(|e| format!("Timed out waiting for gRPC subscription request: {}", e))?
+            .ok_or("gRPC registration channel closed before a request arrived")?;
+        self.available = request.credits;
+        Ok(request)
+    }
+
+    /// Sends `event` down the stream, waiting (subject to [`TIMEOUT`]) for the client to
+    /// grant more credit first if none currently remain. This is the application-level
+    /// backpressure that stands in for the WebSocket transport's per-message receipt,
+    /// layered on top of gRPC's own HTTP/2 stream-level flow control.
+    pub async fn send(&mut self, event: &Event) -> Result<(), String> {
+        while self.available == 0 {
+            self.available = async_std::future::timeout(TIMEOUT, self.credits.next())
+                .await
+                .map_err(|e| format!("Timed out waiting for flow-control credit: {}", e))?
+                .ok_or("Credit channel closed")?;
+        }
+
+        let bytes = VersionedEvent::from(event.clone())
+            .encode_versioned()
+            .map_err(|err| format!("Failed to encode event: {}", err))?;
+        self.events
+            .send(Ok(pb::EventFrame { event: bytes }))
+            .await
+            .map_err(|_| "gRPC subscriber stream closed".to_string())?;
+        self.available -= 1;
+        Ok(())
+    }
+}
+
+/// Long-lived home for gRPC [`Consumer`](super::Consumer)s once their initial backlog
+/// replay (if any) has drained, so the node-wide publish loop has a real, cloneable
+/// handle to forward newly committed events into — unlike the WebSocket transport, whose
+/// `Consumer` is kept alive for free by the task already blocked reading that connection,
+/// nothing else retains a gRPC subscriber's `Consumer` once [`EventStreamService::subscribe`]
+/// returns its response stream.
+///
+/// Cheaply cloned: every [`EventStreamService`] handle built from the same registry shares
+/// the same set of live subscribers.
+#[derive(Debug, Clone, Default)]
+pub struct ConsumerRegistry(Arc<Mutex<Vec<super::Consumer>>>);
+
+impl ConsumerRegistry {
+    /// Constructs an empty registry, with no subscribers yet registered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hands `consumer` a long-lived home in the registry, to be driven by future calls
+    /// to [`ConsumerRegistry::consume`].
+    async fn register(&self, consumer: super::Consumer) {
+        self.0.lock().await.push(consumer);
+    }
+
+    /// Forwards a newly committed `event` (assigned `sequence`, originating from the
+    /// block at `height`) to every registered subscriber. This is the entrypoint the
+    /// node-wide publish loop (outside this crate's event module) is expected to call
+    /// once per committed event, alongside whatever drives the WebSocket transport.
+    ///
+    /// A subscriber whose `consume` fails (e.g. its stream has closed) is dropped from
+    /// the registry rather than retried.
+    pub async fn consume(
+        &self,
+        sequence: super::EventSequence,
+        height: super::BlockHeight,
+        event: &Event,
+    ) {
+        let mut registered = std::mem::take(&mut *self.0.lock().await);
+        let mut still_live = Vec::with_capacity(registered.len());
+        for consumer in registered.drain(..) {
+            if let Ok(consumer) = consumer.consume(sequence, height, event).await {
+                still_live.push(consumer);
+            }
+        }
+        // Extend rather than overwrite, so a subscriber registered while this call was
+        // running is kept.
+        self.0.lock().await.extend(still_live);
+    }
+}
+
+/// The node-side [`pb::event_stream_server::EventStream`] implementation. Bridges the
+/// real tonic bidirectional stream to a [`GrpcStream`]/[`super::Consumer`] pair. Whatever
+/// registers this service with a `tonic::transport::Server` (the node's gRPC entrypoint)
+/// and drives `consumers` by calling [`ConsumerRegistry::consume`] once per committed
+/// event (the same publish loop the WebSocket entrypoint feeds) lives outside this
+/// crate's event module.
+#[derive(Debug, Clone)]
+pub struct EventStreamService {
+    block_commits: super::BlockHeightReceiver,
+    backlog: super::EventBacklog,
+    consumers: ConsumerRegistry,
+}
+
+impl EventStreamService {
+    /// Constructs the service from the same handles the WebSocket entrypoint threads
+    /// through to [`super::Consumer::new`], plus the [`ConsumerRegistry`] the node-wide
+    /// publish loop drives to forward live events into every gRPC subscriber this
+    /// service accepts.
+    pub fn new(
+        block_commits: super::BlockHeightReceiver,
+        backlog: super::EventBacklog,
+        consumers: ConsumerRegistry,
+    ) -> Self {
+        Self {
+            block_commits,
+            backlog,
+            consumers,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl pb::event_stream_server::EventStream for EventStreamService {
+    type SubscribeStream = ReceiverStream<Result<pb::EventFrame, Status>>;
+
+    async fn subscribe(
+        &self,
+        request: Request<Streaming<pb::ClientMessage>>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let mut inbound = request.into_inner();
+        let first = inbound
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("stream closed before registering"))?;
+        let Some(pb::client_message::Payload::Subscribe(subscription)) = first.payload else {
+            return Err(Status::invalid_argument(
+                "the first message on the stream must be a Subscription",
+            ));
+        };
+        let request = GrpcSubscriptionRequest::decode(subscription)?;
+
+        let (registration_tx, registration_rx) = async_std::sync::channel(1);
+        let (credits_tx, credits_rx) = futures_mpsc::channel(1);
+        registration_tx.send(request).await;
+
+        // Forward every `Credit` the client sends after registering into the channel
+        // `GrpcStream::send` waits on; the `Subscription` that opened the stream has
+        // already been consumed above.
+        async_std::task::spawn(async move {
+            let mut credits_tx = credits_tx;
+            while let Ok(Some(message)) = inbound.message().await {
+                if let Some(pb::client_message::Payload::Credit(credit)) = message.payload {
+                    if credits_tx.send(credit.amount).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        let (events_tx, events_rx) = mpsc::channel(INITIAL_CREDITS as usize);
+        let stream = GrpcStream::new(registration_rx, events_tx, credits_rx);
+        // Registers the filter/commitment/cursor and, if resuming, replays the matching
+        // backlog into the `Consumer`'s buffer. Nothing else retains this `Consumer`, so
+        // without the task spawned below it would be dropped here, closing the stream
+        // before any of that backlog is actually sent.
+        let consumer = super::Consumer::new_grpc(stream, self.block_commits.clone(), &self.backlog)
+            .await
+            .map_err(Status::internal)?;
+
+        // Keeps polling the `Consumer` for backlog its `CommitmentLevel` now permits
+        // releasing, until it's drained — which, for a fresh subscription with no resume
+        // cursor, is immediately true. Once drained, hands the `Consumer` to `consumers`
+        // instead of letting it fall out of scope here, so it has a home for the node's
+        // live publish loop to keep driving afterward (see [`ConsumerRegistry`]).
+        let consumers = self.consumers.clone();
+        async_std::task::spawn(async move {
+            let mut consumer = consumer;
+            loop {
+                match consumer.release_buffered().await {
+                    Ok(next) if next.buffer_is_empty() => {
+                        consumers.register(next).await;
+                        break;
+                    }
+                    Ok(next) => {
+                        consumer = next;
+                        async_std::task::sleep(BACKLOG_POLL_INTERVAL).await;
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(events_rx)))
+    }
+}
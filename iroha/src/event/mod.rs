@@ -0,0 +1,283 @@
+//! Iroha is a quite dynamic system so many events can happen.
+//! This module contains descriptions of such an events and
+//! utilitary Iroha Special Instructions to work with them.
+
+use async_std::{
+    future,
+    sync::{Receiver, Sender},
+};
+use futures::{SinkExt, StreamExt};
+use iroha_data_model::events::prelude::*;
+use iroha_http_server::web_socket::{WebSocketMessage, WebSocketStream};
+use iroha_version::prelude::*;
+use std::{collections::VecDeque, fmt::Debug, time::Duration};
+
+mod backlog;
+mod commitment;
+mod grpc;
+mod sink;
+mod standard;
+
+pub use backlog::{EventBacklog, EventSequence};
+use commitment::CommitmentLevelExt;
+pub use commitment::{BlockHeight, BlockHeightReceiver, BlockHeightSender, CommitmentLevel};
+pub use grpc::{pb, ConsumerRegistry, EventStreamService, GrpcStream, GrpcSubscriptionRequest};
+pub use sink::{EventRoute, EventRouter, EventSink};
+pub use standard::{StandardFilter, StandardKey};
+
+/// Request to subscribe to events, decoded from the client's initial WebSocket
+/// registration frame (the gRPC transport receives the same three fields through its own
+/// [`pb::Subscription`] message instead): the filter to match, the finality
+/// [`CommitmentLevel`] to gate release on (chunk0-4), and an optional resume cursor
+/// (chunk0-5).
+///
+/// Declared locally rather than reused from `iroha_data_model::events::SubscriptionRequest`
+/// — that upstream type only carries the filter, and extending it (plus the matching
+/// `iroha_client` request builder that would need to send the extra fields) is a paired
+/// client/data-model change out of scope for this crate. For the same reason this is
+/// decoded as a flat JSON object rather than through `iroha_version`'s versioned envelope,
+/// which is generated at the upstream type's declaration site: `commitment`/`cursor`
+/// default for any client that still only sends `filter`, so this only adds capability,
+/// it doesn't break existing subscribers.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SubscriptionRequest {
+    /// The filter matching events the client wants delivered.
+    pub filter: EventFilter,
+    /// The commitment level events must reach before being released to this subscriber.
+    #[serde(default)]
+    pub commitment: CommitmentLevel,
+    /// The sequence id of the last event this client already received, if resuming.
+    #[serde(default)]
+    pub cursor: Option<EventSequence>,
+}
+
+/// Type of `Sender<Event>` which should be used for channels of `Event` messages.
+pub type EventsSender = Sender<Event>;
+/// Type of `Receiver<Event>` which should be used for channels of `Event` messages.
+pub type EventsReceiver = Receiver<Event>;
+
+const TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// The underlying connection a [`Consumer`] forwards matching [`Event`]s over.
+///
+/// Selected at subscription time by which endpoint the client connected to: the
+/// WebSocket endpoint yields [`Transport::WebSocket`], the gRPC streaming endpoint
+/// yields [`Transport::Grpc`].
+#[derive(Debug)]
+enum Transport {
+    WebSocket(WebSocketStream),
+    Grpc(GrpcStream),
+}
+
+/// Consumer for Iroha `Event`(s).
+/// Passes the events over the corresponding connection `stream` if they match the `filter`.
+///
+/// Events are only released once their originating block satisfies the subscription's
+/// [`CommitmentLevel`]; until then they sit in `buffer`, and `chain_height` (kept current
+/// via `block_commits`) is what unblocks them. `last_sequence` tracks how far the backlog
+/// replay (if any) got, so the transition into the live stream drops duplicates rather
+/// than re-delivering events the client already received.
+#[derive(Debug)]
+pub struct Consumer {
+    transport: Transport,
+    filter: EventFilter,
+    commitment: CommitmentLevel,
+    block_commits: BlockHeightReceiver,
+    chain_height: BlockHeight,
+    last_sequence: Option<EventSequence>,
+    buffer: VecDeque<(BlockHeight, Event)>,
+}
+
+impl Consumer {
+    /// Constructs `Consumer`, which consumes `Event`s and forwards it through the `stream`,
+    /// releasing them once they satisfy the requested [`CommitmentLevel`] as tracked
+    /// through `block_commits`. If the subscription carries a resume cursor, the matching
+    /// backlog is drained from `backlog` before the constructor returns.
+    pub async fn new(
+        mut stream: WebSocketStream,
+        block_commits: BlockHeightReceiver,
+        backlog: &EventBacklog,
+    ) -> Result<Self, String> {
+        if let WebSocketMessage::Text(message) = future::timeout(TIMEOUT, stream.next())
+            .await
+            .map_err(|e| format!("Read message timeout: {}", e))?
+            .ok_or("Failed to read message: no message")?
+            .map_err(|e| format!("Web Socket failure: {}", e))?
+        {
+            let SubscriptionRequest {
+                filter,
+                commitment,
+                cursor,
+            } = serde_json::from_str(&message)
+                .map_err(|e| format!("Failed to parse subscription request: {}", e))?;
+            let (last_sequence, buffer) = replay_backlog(backlog, &filter, cursor).await?;
+            Ok(Consumer {
+                transport: Transport::WebSocket(stream),
+                filter,
+                commitment,
+                block_commits,
+                chain_height: 0,
+                last_sequence,
+                buffer,
+            })
+        } else {
+            Err("Unexepcted message type".to_string())
+        }
+    }
+
+    /// Constructs a `Consumer` over the gRPC streaming transport. The [`EventFilter`],
+    /// [`CommitmentLevel`], and resume cursor are read off the RPC's registration frame,
+    /// after which matching events are pushed to `stream` under its own credit-based flow
+    /// control instead of the WebSocket transport's per-event receipt handshake.
+    pub async fn new_grpc(
+        mut stream: GrpcStream,
+        block_commits: BlockHeightReceiver,
+        backlog: &EventBacklog,
+    ) -> Result<Self, String> {
+        let GrpcSubscriptionRequest {
+            filter,
+            commitment,
+            cursor,
+            ..
+        } = stream.recv_subscription().await?;
+        let (last_sequence, buffer) = replay_backlog(backlog, &filter, cursor).await?;
+        Ok(Consumer {
+            transport: Transport::Grpc(stream),
+            filter,
+            commitment,
+            block_commits,
+            chain_height: 0,
+            last_sequence,
+            buffer,
+        })
+    }
+
+    /// Buffers `event` (assigned `sequence` by the publishing loop, and originating from
+    /// the block at `height`) if it matches the `filter` and hasn't already been
+    /// delivered via backlog replay, then releases every buffered event (including,
+    /// possibly, this one) whose commitment level is now satisfied.
+    ///
+    /// `height` is supplied by the caller rather than read off `event` itself (`Event` has
+    /// no such accessor) — the publishing loop already knows which block it's committing,
+    /// the same way it already assigns `sequence`.
+    pub async fn consume(
+        mut self,
+        sequence: EventSequence,
+        height: BlockHeight,
+        event: &Event,
+    ) -> Result<Self, String> {
+        self.advance_chain_height().await;
+
+        if self.last_sequence.map_or(true, |last| sequence > last) {
+            self.last_sequence = Some(sequence);
+            if self.filter.apply(event) {
+                self.buffer.push_back((height, event.clone()));
+            }
+        }
+
+        self.release_ready().await
+    }
+
+    /// Drives backlog replay without a new live event to hang it off: advances
+    /// `chain_height` from whatever has been committed since, then forwards every
+    /// already-buffered event (seeded by [`Consumer::new_grpc`]'s backlog replay) that now
+    /// satisfies the subscription's [`CommitmentLevel`].
+    ///
+    /// The gRPC streaming endpoint (see [`grpc::EventStreamService::subscribe`]) polls this
+    /// until the buffer is empty, before handing the `Consumer` to its
+    /// [`ConsumerRegistry`], so resumed backlog reaches the subscriber over the real
+    /// connection even when no new live event arrives to drive [`Consumer::consume`]. It
+    /// cannot surface genuinely new events on its own — that still requires the node-wide
+    /// publish loop this crate's event module doesn't own.
+    pub async fn release_buffered(mut self) -> Result<Self, String> {
+        self.advance_chain_height().await;
+        self.release_ready().await
+    }
+
+    /// Whether every buffered event has already been released, i.e. nothing is left for
+    /// [`Consumer::release_buffered`] to do until either the chain advances further or a
+    /// new live event arrives.
+    pub fn buffer_is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Catches `chain_height` up to the latest height observed on `block_commits`, without
+    /// blocking if none has been committed since the last check.
+    async fn advance_chain_height(&mut self) {
+        while let Ok(Some(commit_height)) =
+            future::timeout(Duration::ZERO, self.block_commits.recv()).await
+        {
+            self.chain_height = self.chain_height.max(commit_height);
+        }
+    }
+
+    /// Forwards every buffered event, oldest first, whose commitment level is satisfied by
+    /// the current `chain_height`, stopping at the first one that isn't.
+    async fn release_ready(mut self) -> Result<Self, String> {
+        while let Some((height, _)) = self.buffer.front() {
+            if !self.commitment.is_satisfied_at(*height, self.chain_height) {
+                break;
+            }
+            let (_, ready) = self.buffer.pop_front().expect("checked by front() above");
+            self = self.forward(&ready).await?;
+        }
+
+        Ok(self)
+    }
+
+    /// Unconditionally forwards `event` over the underlying transport.
+    async fn forward(mut self, event: &Event) -> Result<Self, String> {
+        match &mut self.transport {
+            Transport::WebSocket(stream) => {
+                let message = VersionedEvent::from(event.clone())
+                    .to_versioned_json_str()
+                    .map_err(|err| format!("Failed to serialize event: {}", err))?;
+                future::timeout(TIMEOUT, stream.send(WebSocketMessage::Text(message)))
+                    .await
+                    .map_err(|e| format!("Read message timeout: {}", e))?
+                    .map_err(|e| format!("Failed to write message: {}", e))?;
+                if let WebSocketMessage::Text(receipt) = future::timeout(TIMEOUT, stream.next())
+                    .await
+                    .map_err(|e| format!("Failed to read receipt: {}", e))?
+                    .ok_or("Failed to read receipt: no receipt")?
+                    .map_err(|e| format!("Web Socket failure: {}", e))?
+                {
+                    let _receipt =
+                        VersionedEventReceived::from_versioned_json_str(&receipt).map_err(|_| {
+                            format!("Unexpected message, waited for receipt got: {}", receipt)
+                        })?;
+                } else {
+                    return Err("Unexepcted message type".to_string());
+                }
+            }
+            Transport::Grpc(stream) => stream.send(event).await?,
+        }
+        Ok(self)
+    }
+}
+
+/// Drains `backlog` from `cursor` (exclusive) if one was requested, seeding a fresh
+/// `Consumer`'s commitment buffer with the filter-matching backlog and returning the
+/// sequence id to resume live delivery from.
+async fn replay_backlog(
+    backlog: &EventBacklog,
+    filter: &EventFilter,
+    cursor: Option<EventSequence>,
+) -> Result<(Option<EventSequence>, VecDeque<(BlockHeight, Event)>), String> {
+    let Some(cursor) = cursor else {
+        return Ok((None, VecDeque::new()));
+    };
+    let replay = backlog
+        .replay_since(cursor)
+        .await
+        .ok_or("requested resume cursor has fallen out of the retained backlog window")?;
+    let mut last_sequence = cursor;
+    let mut buffer = VecDeque::new();
+    for (sequence, height, event) in replay {
+        last_sequence = sequence;
+        if filter.apply(&event) {
+            buffer.push_back((height, event));
+        }
+    }
+    Ok((Some(last_sequence), buffer))
+}
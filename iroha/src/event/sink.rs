@@ -0,0 +1,110 @@
+//! Durable event export via pluggable [`EventSink`]s and an [`EventRouter`] routing table.
+//!
+//! Where [`Consumer`](super::Consumer) only reaches a client through a live connection,
+//! a route lets an operator fan a matching subset of events out to something that
+//! outlives the connection: a file/JSONL writer, an HTTP webhook, a message-queue
+//! publisher, and so on.
+
+use async_trait::async_trait;
+use futures::future::join_all;
+use iroha_data_model::events::prelude::*;
+use std::{fmt::Debug, sync::Arc, time::Duration};
+
+use super::{StandardFilter, StandardKey, TIMEOUT};
+
+/// A destination events can be durably exported to.
+#[async_trait]
+pub trait EventSink: Debug + Send + Sync {
+    /// Processes a single `event` already known to match this sink's route.
+    async fn process(&self, event: &Event) -> Result<(), String>;
+}
+
+/// One entry in the [`EventRouter`]'s table: every event matching `filter` (and, if set,
+/// `standard`) is dispatched to `sink`, bounded by `timeout`.
+#[derive(Debug, Clone)]
+pub struct EventRoute {
+    filter: EventFilter,
+    standard: Option<StandardFilter>,
+    sink: Arc<dyn EventSink>,
+    timeout: Duration,
+}
+
+impl EventRoute {
+    /// Constructs a route dispatching events matching `filter` to `sink`, using the
+    /// default per-sink [`TIMEOUT`].
+    pub fn new(filter: EventFilter, sink: Arc<dyn EventSink>) -> Self {
+        Self {
+            filter,
+            standard: None,
+            sink,
+            timeout: TIMEOUT,
+        }
+    }
+
+    /// Constructs a route dispatching only events self-describing as `key` (see
+    /// [`StandardFilter`]) to `sink`, so an indexer can be scoped to a named standard like
+    /// `"iroha-rbac"` without hard-coding which [`Event`] variants carry it.
+    pub fn for_standard(filter: EventFilter, key: StandardKey, sink: Arc<dyn EventSink>) -> Self {
+        Self {
+            filter,
+            standard: Some(StandardFilter::new(key)),
+            sink,
+            timeout: TIMEOUT,
+        }
+    }
+
+    /// Overrides the default dispatch timeout for this route.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Whether `event` satisfies both this route's `filter` and, if set, its `standard`.
+    fn matches(&self, event: &Event) -> bool {
+        self.filter.apply(event) && self.standard.as_ref().map_or(true, |s| s.apply(event))
+    }
+}
+
+/// Fans events out to every registered [`EventRoute`] whose filter matches.
+///
+/// A delivery error on one route (a slow webhook, a full queue) is reported back to the
+/// caller but never prevents the remaining routes from being tried.
+#[derive(Debug, Clone, Default)]
+pub struct EventRouter {
+    routes: Vec<EventRoute>,
+}
+
+impl EventRouter {
+    /// Constructs an empty router.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a new route, returning `self` for chained construction.
+    #[must_use]
+    pub fn with_route(mut self, route: EventRoute) -> Self {
+        self.routes.push(route);
+        self
+    }
+
+    /// Dispatches `event` to every route whose filter matches it, in parallel, returning
+    /// the delivery errors of any routes that failed (by index into the routing table).
+    pub async fn route(&self, event: &Event) -> Vec<(usize, String)> {
+        let dispatches = self.routes.iter().enumerate().filter_map(|(i, route)| {
+            route.matches(event).then(|| async move {
+                async_std::future::timeout(route.timeout, route.sink.process(event))
+                    .await
+                    .map_err(|e| format!("Sink dispatch timed out: {}", e))
+                    .and_then(std::convert::identity)
+                    .map_err(|e| (i, e))
+            })
+        });
+
+        join_all(dispatches)
+            .await
+            .into_iter()
+            .filter_map(Result::err)
+            .collect()
+    }
+}
@@ -0,0 +1,72 @@
+//! Standard-name/version envelope matching for self-describing events.
+//!
+//! Iroha's own [`Event`] variants are identified by Rust enum shape, which off-chain
+//! indexers would otherwise have to hard-code just to tell one kind of domain event from
+//! another. An event that opts into a named standard (for example the RBAC
+//! `PermissionChanged` event emitted for `standard = "iroha-rbac"`) additionally carries a
+//! `standard` name and a `version` in its serialized body; [`StandardFilter`] matches on
+//! those two fields alone, so a subscriber can route on `(standard, version)` without
+//! knowing the underlying variant at all.
+//!
+//! This is additive to [`EventFilter`]'s existing variant-shaped matching, not a
+//! replacement for it: the raw data-event stream keeps working exactly as before, and a
+//! route or subscription can require both filters to match.
+
+use iroha_data_model::events::prelude::*;
+use iroha_version::prelude::*;
+use serde_json::Value;
+
+/// Identifies one namespaced event standard at a given version, e.g. `("iroha-rbac", 1)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StandardKey {
+    standard: String,
+    version: u32,
+}
+
+impl StandardKey {
+    /// Identifies `standard` at `version`.
+    pub fn new(standard: impl Into<String>, version: u32) -> Self {
+        Self {
+            standard: standard.into(),
+            version,
+        }
+    }
+}
+
+/// Matches events that self-describe as a particular [`StandardKey`] in their serialized
+/// JSON body, regardless of which [`Event`] variant actually carries the data.
+///
+/// An event with no `standard`/`version` fields (i.e. anything that hasn't opted into a
+/// named standard) never matches: this is for subscribers that specifically want a named
+/// standard's events, not a general-purpose replacement for [`EventFilter`].
+#[derive(Debug, Clone)]
+pub struct StandardFilter {
+    key: StandardKey,
+}
+
+impl StandardFilter {
+    /// Matches only events self-describing as `key`.
+    pub fn new(key: StandardKey) -> Self {
+        Self { key }
+    }
+
+    /// Whether `event` self-describes as this filter's [`StandardKey`].
+    pub fn apply(&self, event: &Event) -> bool {
+        let Ok(json) = VersionedEvent::from(event.clone()).to_versioned_json_str() else {
+            return false;
+        };
+        let Ok(value) = serde_json::from_str::<Value>(&json) else {
+            return false;
+        };
+        standard_key_of(&value).as_ref() == Some(&self.key)
+    }
+}
+
+/// Reads the `standard`/`version` fields out of an event's serialized JSON body, if
+/// present. Events that haven't opted into a named standard simply don't have these
+/// fields and so never produce a [`StandardKey`] here.
+fn standard_key_of(value: &Value) -> Option<StandardKey> {
+    let standard = value.get("standard")?.as_str()?;
+    let version = value.get("version")?.as_u64()?;
+    Some(StandardKey::new(standard, u32::try_from(version).ok()?))
+}
@@ -11,6 +11,11 @@ use crate::permission::{self, Token as _};
 /// Declare token types of current module. Use it with a full path to the token.
 /// Used to iterate over tokens to validate `Mint` and `Burn` instructions.
 ///
+/// A parameterless/global entry may be tagged `@ bit` to also be assigned a stable bit
+/// index, folded into the `to_bits`/`from_bits` conversions on `AnyPermissionToken` used
+/// by [`bitset::grant_all`]/[`bitset::revoke_all`]. Tokens carrying an id field (domain,
+/// account, asset, ...) must never be tagged: a single bit can't distinguish which entity
+/// the capability applies to.
 ///
 /// Example:
 ///
@@ -28,7 +33,7 @@ use crate::permission::{self, Token as _};
 /// }
 /// ```
 macro_rules! declare_tokens {
-    ($($($token_path:ident ::)+ { $token_ty:ident }),+ $(,)?) => {
+    ($($($token_path:ident ::)+ { $token_ty:ident } $(@ $bit:ident)?),+ $(,)?) => {
         macro_rules! map_token_type {
             ($callback:ident) => { $(
                 $callback!($($token_path::)+$token_ty); )+
@@ -36,16 +41,31 @@ macro_rules! declare_tokens {
         }
 
         /// Enum with every default token
+        ///
+        /// `PartialEq`/`Eq` make two tokens parsed from differently-formatted but
+        /// semantically equal `PermissionToken`s (e.g. differing whitespace, or an
+        /// asset id written in its long vs. shorthand form) compare equal once parsed,
+        /// which is what lets a schema-migration pass collapse duplicates: compare the
+        /// `AnyPermissionToken` values, not the raw `PermissionToken` bytes.
         #[allow(clippy::enum_variant_names)]
-        #[derive(Clone)]
+        #[derive(Clone, PartialEq, Eq)]
         pub(crate) enum AnyPermissionToken { $(
             $token_ty($($token_path::)+$token_ty), )*
+            /// Any other variant, granted only until `expires_at_block` (`0` meaning
+            /// "never expires"). See [`ExpiringToken`] for the wire encoding and
+            /// [`AnyPermissionToken::resolve`] for how callers should treat a lapsed one.
+            Expiring(alloc::boxed::Box<AnyPermissionToken>, u64),
         }
 
         impl TryFrom<&$crate::data_model::permission::PermissionToken> for AnyPermissionToken {
             type Error = $crate::permission::PermissionTokenConversionError;
 
             fn try_from(token: &$crate::data_model::permission::PermissionToken) -> Result<Self, Self::Error> {
+                if token.definition_id().as_ref() == stringify!(ExpiringToken) {
+                    let wrapper = ExpiringToken::try_from(token)?;
+                    let inner = AnyPermissionToken::try_from(&wrapper.token)?;
+                    return Ok(Self::Expiring(alloc::boxed::Box::new(inner), wrapper.expires_at_block));
+                }
                 match token.definition_id().as_ref() { $(
                     stringify!($token_ty) => {
                         let token = <$($token_path::)+$token_ty>::try_from(token)?;
@@ -60,6 +80,11 @@ macro_rules! declare_tokens {
             fn from(token: AnyPermissionToken) -> Self {
                 match token { $(
                     AnyPermissionToken::$token_ty(token) => Self::from(token), )*
+                    AnyPermissionToken::Expiring(inner, expires_at_block) => ExpiringToken {
+                        token: Self::from(*inner),
+                        expires_at_block,
+                    }
+                    .into(),
                 }
             }
         }
@@ -68,20 +93,168 @@ macro_rules! declare_tokens {
             fn validate_grant(&self, authority: &AccountId, block_height: u64) -> Result {
                 match self { $(
                     AnyPermissionToken::$token_ty(token) => token.validate_grant(authority, block_height), )*
+                    AnyPermissionToken::Expiring(inner, expires_at_block) => {
+                        if *expires_at_block != 0 && block_height >= *expires_at_block {
+                            return Err(ValidationFail::NotPermitted(
+                                "Cannot grant a token that has already expired".to_owned(),
+                            ));
+                        }
+                        inner.validate_grant(authority, block_height)
+                    }
                 }
             }
 
             fn validate_revoke(&self, authority: &AccountId, block_height: u64) -> Result {
                 match self { $(
                     AnyPermissionToken::$token_ty(token) => token.validate_revoke(authority, block_height), )*
+                    AnyPermissionToken::Expiring(inner, _expires_at_block) => {
+                        inner.validate_revoke(authority, block_height)
+                    }
                 }
             }
         }
 
         pub(crate) use map_token_type;
+
+        declare_tokens!(@bitset [] 0u64; $($($token_path::)+ { $token_ty } $(@ $bit)?,)+);
+    };
+
+    // Base case: every entry has been munched, emit the bit conversions from what the
+    // accumulator collected along the way.
+    (@bitset [$($acc_ty:ident @ $acc_bit:expr => $acc_path:path),*] $_next:expr;) => {
+        impl AnyPermissionToken {
+            /// Encodes this token as a `u64` bitmask, if it is one of the
+            /// stable-width, parameterless global tokens tagged `@ bit` above. Tokens
+            /// that carry an id field are not representable this way and return `None`.
+            pub(crate) fn to_bits(&self) -> Option<u64> {
+                match self { $(
+                    Self::$acc_ty(_) => Some(1u64 << $acc_bit), )*
+                    _ => None,
+                }
+            }
+
+            /// Decodes every set bit of `mask` back into its `AnyPermissionToken`,
+            /// ignoring bits that don't correspond to a known global token.
+            pub(crate) fn from_bits(mask: u64) -> Vec<Self> {
+                let mut tokens = Vec::new();
+                $(
+                    if mask & (1u64 << $acc_bit) != 0 {
+                        tokens.push(Self::$acc_ty($acc_path));
+                    }
+                )*
+                tokens
+            }
+        }
+    };
+
+    // A `@ bit`-tagged entry: record it in the accumulator and bump the bit counter.
+    (@bitset [$($acc_ty:ident @ $acc_bit:expr => $acc_path:path),*] $next:expr;
+     $($token_path:ident ::)+ { $token_ty:ident } @ $bit_marker:ident, $($rest:tt)*) => {
+        declare_tokens!(@bitset
+            [$($acc_ty @ $acc_bit => $acc_path,)* $token_ty @ $next => $($token_path::)+$token_ty]
+            ($next + 1u64);
+            $($rest)*
+        );
+    };
+
+    // A plain (parameterized) entry: skip it, the bit counter doesn't advance.
+    (@bitset [$($acc_ty:ident @ $acc_bit:expr => $acc_path:path),*] $next:expr;
+     $($token_path:ident ::)+ { $token_ty:ident }, $($rest:tt)*) => {
+        declare_tokens!(@bitset
+            [$($acc_ty @ $acc_bit => $acc_path),*]
+            $next;
+            $($rest)*
+        );
     };
 }
 
+/// Batch grant/revoke of the `@ bit`-tagged global tokens declared in [`declare_tokens!`],
+/// expressing "all executor/parameter capabilities" (or any other subset) as one compact
+/// `u64` mask instead of many separate `Mint`/`Burn` of individual `PermissionToken`s.
+pub(crate) mod bitset {
+    use super::{AccountId, AnyPermissionToken, Result};
+    use crate::permission::ValidateGrantRevoke as _;
+
+    /// Validates granting every token set in `mask` to `authority`, reusing each token's
+    /// existing [`ValidateGrantRevoke::validate_grant`] check.
+    pub(crate) fn grant_all(mask: u64, authority: &AccountId, block_height: u64) -> Result {
+        AnyPermissionToken::from_bits(mask)
+            .iter()
+            .try_for_each(|token| token.validate_grant(authority, block_height))
+    }
+
+    /// Validates revoking every token set in `mask` from `authority`, reusing each
+    /// token's existing [`ValidateGrantRevoke::validate_revoke`] check.
+    pub(crate) fn revoke_all(mask: u64, authority: &AccountId, block_height: u64) -> Result {
+        AnyPermissionToken::from_bits(mask)
+            .iter()
+            .try_for_each(|token| token.validate_revoke(authority, block_height))
+    }
+
+    /// Mask bundling every `@ bit`-tagged administrative token declared in
+    /// [`declare_tokens!`] — the batch [`grant_all`]/[`revoke_all`] shape a genesis
+    /// block uses to hand (or later strip) every global capability from a single
+    /// admin account at once, instead of minting/burning them one `PermissionToken`
+    /// at a time.
+    ///
+    /// Computed by round-tripping every bit through
+    /// [`AnyPermissionToken::from_bits`]/[`AnyPermissionToken::to_bits`] rather than
+    /// hardcoding bit positions, so it stays correct as tokens are added to or
+    /// reordered in `declare_tokens!`.
+    pub(crate) fn all_tokens_mask() -> u64 {
+        AnyPermissionToken::from_bits(u64::MAX)
+            .iter()
+            .filter_map(AnyPermissionToken::to_bits)
+            .fold(0, |mask, bit| mask | bit)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn genesis() -> AccountId {
+            "genesis@genesis".parse().expect("valid account id")
+        }
+
+        fn other() -> AccountId {
+            "alice@wonderland".parse().expect("valid account id")
+        }
+
+        #[test]
+        fn all_tokens_mask_round_trips_through_to_bits() {
+            let mask = all_tokens_mask();
+            assert_ne!(mask, 0, "declare_tokens! tags at least one `@ bit` token");
+            let round_tripped = AnyPermissionToken::from_bits(mask)
+                .iter()
+                .filter_map(AnyPermissionToken::to_bits)
+                .fold(0, |acc, bit| acc | bit);
+            assert_eq!(mask, round_tripped, "every bit decoded by from_bits must re-encode to itself");
+        }
+
+        #[test]
+        fn grant_all_and_revoke_all_defer_to_each_bundled_token_s_own_check() {
+            // Restricted to the purely account-gated (`OnlyGenesis`) bits: the
+            // parameter tokens validated against another token's *ownership*
+            // (`CanCreateParameters`/`CanSetParameters`) need a live WSV query this
+            // crate's unit tests don't have a host for.
+            let mask = AnyPermissionToken::CanUnregisterAnyPeer(super::super::peer::CanUnregisterAnyPeer)
+                .to_bits()
+                .expect("tagged `@ bit`")
+                | AnyPermissionToken::CanUpgradeExecutor(super::super::executor::CanUpgradeExecutor)
+                    .to_bits()
+                    .expect("tagged `@ bit`")
+                | AnyPermissionToken::CanPauseChain(super::super::chain::CanPauseChain)
+                    .to_bits()
+                    .expect("tagged `@ bit`");
+
+            grant_all(mask, &genesis(), 0).expect("genesis may grant every OnlyGenesis-gated token");
+            grant_all(mask, &other(), 0)
+                .expect_err("a non-genesis account must not be able to grant the bundle");
+            revoke_all(mask, &genesis(), 0).expect("genesis may revoke every OnlyGenesis-gated token");
+        }
+    }
+}
+
 macro_rules! token {
     ($($meta:meta)* $item:item) => {
         #[derive(PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -92,14 +265,380 @@ macro_rules! token {
     };
 }
 
+/// Governs who may grant or revoke a [`declare_roles!`]-bundled role once it is
+/// registered. Mirrors the `Fixed` / `Updatable` / `None` owner states of Radix's
+/// `OwnerRoleEntry`.
+#[derive(Clone)]
+pub(crate) enum OwnerRolePolicy {
+    /// The role's assignment can never be changed, not even by genesis.
+    Fixed,
+    /// Only this account may grant or revoke the role's membership.
+    Updatable(AccountId),
+    /// No extra restriction beyond the bundled tokens' own grant/revoke checks.
+    None,
+}
+
+impl OwnerRolePolicy {
+    fn validate(&self, authority: &AccountId) -> Result {
+        match self {
+            Self::Fixed => Err(ValidationFail::NotPermitted(
+                "This role's assignment is fixed and can never be changed".to_owned(),
+            )),
+            Self::Updatable(owner) if authority == owner => Ok(()),
+            Self::Updatable(owner) => Err(ValidationFail::NotPermitted(format!(
+                "Only `{owner}` may change who holds this role"
+            ))),
+            Self::None => Ok(()),
+        }
+    }
+}
+
+/// Declare named bundles of [`AnyPermissionToken`]s as roles, generating `AnyRole`, the
+/// role-level counterpart of `AnyPermissionToken`. Every bundled role carries an
+/// [`OwnerRolePolicy`], consulted by the generated `ValidateGrantRevoke` impl before
+/// delegating to each bundled token's own grant/revoke check, so a role's capabilities
+/// and the rule for who may reassign it are validated together in one instruction.
+///
+///
+/// Example:
+///
+/// ```ignore
+/// declare_roles! {
+///     DomainAdmin: OwnerRolePolicy::None,
+///     CoreOperator: OwnerRolePolicy::Fixed,
+/// }
+/// ```
+macro_rules! declare_roles {
+    ($($role_ty:ident: $policy:expr),+ $(,)?) => {
+        /// Enum bundling a coherent set of `AnyPermissionToken`s under a named role,
+        /// parallel to `AnyPermissionToken` itself.
+        #[allow(clippy::enum_variant_names)]
+        #[derive(Clone)]
+        pub(crate) enum AnyRole { $(
+            $role_ty(Vec<AnyPermissionToken>), )*
+        }
+
+        impl AnyRole {
+            fn owner_policy(&self) -> OwnerRolePolicy {
+                match self { $(
+                    Self::$role_ty(_) => $policy, )*
+                }
+            }
+        }
+
+        impl From<AnyRole> for Vec<$crate::data_model::permission::PermissionToken> {
+            fn from(role: AnyRole) -> Self {
+                let tokens: Vec<$crate::data_model::permission::PermissionToken> = match role { $(
+                    AnyRole::$role_ty(tokens) => {
+                        tokens.into_iter().map(Into::into).collect()
+                    } )*
+                };
+                // Bundled tokens are built by hand (see `AnyRole::domain_admin`/
+                // `core_operator`) so they shouldn't carry duplicates today, but
+                // collapsing here means a role never hands out the same capability twice
+                // even if a future bundle is assembled less carefully.
+                AnyPermissionToken::dedup(tokens)
+            }
+        }
+
+        impl $crate::permission::ValidateGrantRevoke for AnyRole {
+            fn validate_grant(&self, authority: &AccountId, block_height: u64) -> Result {
+                self.owner_policy().validate(authority)?;
+                match self { $(
+                    Self::$role_ty(tokens) => tokens
+                        .iter()
+                        .try_for_each(|token| token.validate_grant(authority, block_height)), )*
+                }
+            }
+
+            fn validate_revoke(&self, authority: &AccountId, block_height: u64) -> Result {
+                self.owner_policy().validate(authority)?;
+                match self { $(
+                    Self::$role_ty(tokens) => tokens
+                        .iter()
+                        .try_for_each(|token| token.validate_revoke(authority, block_height)), )*
+                }
+            }
+        }
+    };
+}
+
+/// Out-of-band pending-owner bookkeeping shared by every `CanPropose*Ownership` /
+/// `CanAccept*Ownership` token pair declared via [`declare_ownership_handover!`].
+///
+/// Mirrors `asset::allowance`'s approach of keeping mutable state in a metadata entry
+/// rather than in the (immutable) permission token: the entity's current owner proposes
+/// a successor, which only the named successor may then accept, moving control over
+/// without risking an irreversible transfer to an unreachable account.
+pub(crate) mod handover {
+    use super::*;
+
+    /// The per-entity metadata key holding the pending owner, namespaced by entity kind
+    /// so domain, account and asset-definition handovers can't collide.
+    ///
+    /// `entity_id` is an id `Display`, which for composite ids (`AccountId`, `AssetId`, ...)
+    /// contains the very `#`/`@` separators `Name` parsing rejects, so those are replaced
+    /// with `_` before parsing rather than passed through raw.
+    fn pending_owner_key(entity_kind: &str, entity_id: &str) -> Name {
+        let entity_id = entity_id.replace(['#', '@'], "_");
+        format!("pending_owner/{entity_kind}/{entity_id}")
+            .parse()
+            .expect("valid metadata key")
+    }
+
+    /// Records `proposed_owner` as the pending owner of `entity_id`, overwriting (and so
+    /// implicitly revoking) any earlier proposal for the same entity.
+    pub(crate) fn propose<F>(entity_kind: &str, entity_id: &str, proposed_owner: &AccountId, set: F) -> Result
+    where
+        F: FnOnce(Name, String) -> Result,
+    {
+        set(
+            pending_owner_key(entity_kind, entity_id),
+            proposed_owner.to_string(),
+        )
+    }
+
+    /// Clears any pending-owner proposal for `entity_id`, e.g. because the current owner
+    /// revoked the `CanPropose*Ownership` token that created it.
+    pub(crate) fn cancel<F>(entity_kind: &str, entity_id: &str, remove: F) -> Result
+    where
+        F: FnOnce(Name) -> Result,
+    {
+        remove(pending_owner_key(entity_kind, entity_id))
+    }
+
+    /// Validates that `authority` is the account named by `entity_id`'s pending-owner
+    /// proposal, then clears the proposal: acceptance is one-shot.
+    pub(crate) fn accept<F, G>(
+        entity_kind: &str,
+        entity_id: &str,
+        authority: &AccountId,
+        find: F,
+        remove: G,
+    ) -> Result
+    where
+        F: FnOnce(Name) -> Option<String>,
+        G: FnOnce(Name) -> Result,
+    {
+        let key = pending_owner_key(entity_kind, entity_id);
+        let proposed_owner = find(key.clone()).ok_or_else(|| {
+            ValidationFail::NotPermitted(
+                "No ownership handover has been proposed for this entity".to_owned(),
+            )
+        })?;
+        if proposed_owner != authority.to_string() {
+            return Err(ValidationFail::NotPermitted(
+                "Only the proposed owner may accept this ownership handover".to_owned(),
+            ));
+        }
+        remove(key)
+    }
+
+    #[cfg(test)]
+    mod pending_owner_key_tests {
+        use super::*;
+
+        #[test]
+        fn pending_owner_key_parses_for_a_composite_entity_id() {
+            let asset_id: AssetId = "rose#wonderland#alice@wonderland"
+                .parse()
+                .expect("valid asset id");
+            // `AssetId`'s `Display` contains `#`/`@`; `pending_owner_key` must not panic
+            // building a `Name` out of it.
+            let _ = pending_owner_key("asset_definition", &asset_id.to_string());
+        }
+    }
+
+    /// Exercises `propose`/`cancel`/`accept` directly against an in-memory stand-in for
+    /// the metadata entry `pending_owner_key` addresses, since the real `SetKeyValue`/
+    /// `FindDomainKeyValueByIdAndKey` instructions these are wired to (see
+    /// `declare_ownership_handover!`) only execute inside a live smart-contract host.
+    ///
+    /// These are the behavioural cases chunk2-3 asked a domain-ownership handover prove:
+    /// only the proposed owner may accept, and proposing a new recipient implicitly
+    /// supersedes the previous proposal rather than requiring an explicit cancel. The
+    /// `Transfer::domain`/`AcceptDomainOwnership` instruction pair chunk2-3 actually asked
+    /// for would additionally need `iroha_data_model` and WSV changes this crate doesn't
+    /// contain, so this tests the same propose/accept pattern chunk1-4 already shipped
+    /// here instead of re-adding a dependency on instructions that don't exist in this
+    /// tree.
+    #[cfg(test)]
+    mod handover_tests {
+        use core::cell::RefCell;
+
+        use super::*;
+
+        fn entity_id() -> AccountId {
+            "mouse@wonderland".parse().expect("valid account id")
+        }
+
+        #[test]
+        fn only_the_proposed_owner_may_accept() {
+            let store: RefCell<Option<(Name, String)>> = RefCell::new(None);
+            let bob: AccountId = "bob@wonderland".parse().expect("valid account id");
+            let eve: AccountId = "eve@wonderland".parse().expect("valid account id");
+
+            propose("account", &entity_id().to_string(), &bob, |key, value| {
+                *store.borrow_mut() = Some((key, value));
+                Ok(())
+            })
+            .expect("propose should succeed");
+
+            let err = accept(
+                "account",
+                &entity_id().to_string(),
+                &eve,
+                |key| find(&store, key),
+                |key| remove(&store, key),
+            )
+            .expect_err("only the proposed owner should be able to accept");
+            assert!(matches!(err, ValidationFail::NotPermitted(_)));
+            assert!(
+                store.borrow().is_some(),
+                "a rejected accept must leave the pending proposal (and so ownership) unchanged"
+            );
+
+            accept(
+                "account",
+                &entity_id().to_string(),
+                &bob,
+                |key| find(&store, key),
+                |key| remove(&store, key),
+            )
+            .expect("the proposed owner should be able to accept");
+            assert!(
+                store.borrow().is_none(),
+                "accept should clear the pending proposal, since acceptance is one-shot"
+            );
+        }
+
+        #[test]
+        fn proposing_a_new_owner_implicitly_supersedes_the_previous_proposal() {
+            let store: RefCell<Option<(Name, String)>> = RefCell::new(None);
+            let bob: AccountId = "bob@wonderland".parse().expect("valid account id");
+            let eve: AccountId = "eve@wonderland".parse().expect("valid account id");
+
+            propose("account", &entity_id().to_string(), &bob, |key, value| {
+                *store.borrow_mut() = Some((key, value));
+                Ok(())
+            })
+            .expect("propose should succeed");
+            propose("account", &entity_id().to_string(), &eve, |key, value| {
+                *store.borrow_mut() = Some((key, value));
+                Ok(())
+            })
+            .expect("re-proposing should succeed");
+
+            let err = accept(
+                "account",
+                &entity_id().to_string(),
+                &bob,
+                |key| find(&store, key),
+                |key| remove(&store, key),
+            )
+            .expect_err("the superseded proposal must no longer be acceptable");
+            assert!(matches!(err, ValidationFail::NotPermitted(_)));
+        }
+
+        fn find(store: &RefCell<Option<(Name, String)>>, key: Name) -> Option<String> {
+            store
+                .borrow()
+                .as_ref()
+                .filter(|(k, _)| *k == key)
+                .map(|(_, v)| v.clone())
+        }
+
+        fn remove(store: &RefCell<Option<(Name, String)>>, key: Name) -> Result {
+            let mut store = store.borrow_mut();
+            assert_eq!(store.as_ref().map(|(k, _)| k.clone()), Some(key));
+            *store = None;
+            Ok(())
+        }
+    }
+}
+
+/// Declares the `CanPropose*Ownership` / `CanAccept*Ownership` token pair implementing
+/// the two-step ownership-handover pattern (propose, then accept) for one entity kind,
+/// following the same current-owner-validated shape as the other tokens in its module.
+///
+/// `$propose_ty` is validated like `$owner` validates the other tokens declared
+/// alongside it, then records `proposed_owner` as the entity's pending owner via
+/// [`handover::propose`]; revoking `$propose_ty` clears that pending entry via
+/// [`handover::cancel`]. Only the account named by a pending proposal may grant (mint)
+/// the paired `$accept_ty`, which clears the entry via [`handover::accept`] once
+/// accepted — there is no corresponding revoke, since acceptance is one-shot.
+macro_rules! declare_ownership_handover {
+    (
+        entity_kind: $entity_kind:literal,
+        id: $id_ty:ident :: $id_field:ident,
+        owner: $owner:path,
+        derive_conversions: $derive_conv:path,
+        key_value: { get: $get_kv:expr, set: $set_kv:expr, remove: $remove_kv:expr },
+        tokens: $propose_ty:ident, $accept_ty:ident $(,)?
+    ) => {
+        token! {
+            #[derive($derive_conv)]
+            pub struct $propose_ty {
+                pub $id_field: $id_ty,
+                pub proposed_owner: AccountId,
+            }
+        }
+
+        impl permission::ValidateGrantRevoke for $propose_ty {
+            fn validate_grant(&self, authority: &AccountId, block_height: u64) -> Result {
+                <Self as $owner>::validate_grant(self, authority, block_height)?;
+                handover::propose(
+                    $entity_kind,
+                    &self.$id_field.to_string(),
+                    &self.proposed_owner,
+                    |key, value| $set_kv(&self.$id_field, key, value),
+                )
+            }
+
+            fn validate_revoke(&self, authority: &AccountId, block_height: u64) -> Result {
+                <Self as $owner>::validate_revoke(self, authority, block_height)?;
+                handover::cancel(
+                    $entity_kind,
+                    &self.$id_field.to_string(),
+                    |key| $remove_kv(&self.$id_field, key),
+                )
+            }
+        }
+
+        token! {
+            pub struct $accept_ty {
+                pub $id_field: $id_ty,
+            }
+        }
+
+        impl permission::ValidateGrantRevoke for $accept_ty {
+            fn validate_grant(&self, authority: &AccountId, _block_height: u64) -> Result {
+                handover::accept(
+                    $entity_kind,
+                    &self.$id_field.to_string(),
+                    authority,
+                    |key| $get_kv(&self.$id_field, key),
+                    |key| $remove_kv(&self.$id_field, key),
+                )
+            }
+
+            fn validate_revoke(&self, _authority: &AccountId, _block_height: u64) -> Result {
+                Ok(())
+            }
+        }
+    };
+}
+
 declare_tokens! {
-    crate::default::tokens::peer::{CanUnregisterAnyPeer},
+    crate::default::tokens::peer::{CanUnregisterAnyPeer} @ bit,
 
     crate::default::tokens::domain::{CanUnregisterDomain},
     crate::default::tokens::domain::{CanSetKeyValueInDomain},
     crate::default::tokens::domain::{CanRemoveKeyValueInDomain},
     crate::default::tokens::domain::{CanRegisterAccountInDomain},
     crate::default::tokens::domain::{CanRegisterAssetDefinitionInDomain},
+    crate::default::tokens::domain::{CanProposeDomainOwnership},
+    crate::default::tokens::domain::{CanAcceptDomainOwnership},
 
     crate::default::tokens::account::{CanUnregisterAccount},
     crate::default::tokens::account::{CanMintUserPublicKeys},
@@ -107,10 +646,14 @@ declare_tokens! {
     crate::default::tokens::account::{CanMintUserSignatureCheckConditions},
     crate::default::tokens::account::{CanSetKeyValueInUserAccount},
     crate::default::tokens::account::{CanRemoveKeyValueInUserAccount},
+    crate::default::tokens::account::{CanProposeAccountOwnership},
+    crate::default::tokens::account::{CanAcceptAccountOwnership},
 
     crate::default::tokens::asset_definition::{CanUnregisterAssetDefinition},
     crate::default::tokens::asset_definition::{CanSetKeyValueInAssetDefinition},
     crate::default::tokens::asset_definition::{CanRemoveKeyValueInAssetDefinition},
+    crate::default::tokens::asset_definition::{CanProposeAssetDefinitionOwnership},
+    crate::default::tokens::asset_definition::{CanAcceptAssetDefinitionOwnership},
 
     crate::default::tokens::asset::{CanRegisterAssetWithDefinition},
     crate::default::tokens::asset::{CanUnregisterAssetWithDefinition},
@@ -121,24 +664,142 @@ declare_tokens! {
     crate::default::tokens::asset::{CanBurnUserAsset},
     crate::default::tokens::asset::{CanTransferAssetWithDefinition},
     crate::default::tokens::asset::{CanTransferUserAsset},
+    crate::default::tokens::asset::{CanTransferUserAssetUpToAmount},
     crate::default::tokens::asset::{CanSetKeyValueInUserAsset},
     crate::default::tokens::asset::{CanRemoveKeyValueInUserAsset},
 
-    crate::default::tokens::parameter::{CanGrantPermissionToCreateParameters},
-    crate::default::tokens::parameter::{CanRevokePermissionToCreateParameters},
-    crate::default::tokens::parameter::{CanCreateParameters},
-    crate::default::tokens::parameter::{CanGrantPermissionToSetParameters},
-    crate::default::tokens::parameter::{CanRevokePermissionToSetParameters},
-    crate::default::tokens::parameter::{CanSetParameters},
+    crate::default::tokens::parameter::{CanGrantPermissionToCreateParameters} @ bit,
+    crate::default::tokens::parameter::{CanRevokePermissionToCreateParameters} @ bit,
+    crate::default::tokens::parameter::{CanCreateParameters} @ bit,
+    crate::default::tokens::parameter::{CanGrantPermissionToSetParameters} @ bit,
+    crate::default::tokens::parameter::{CanRevokePermissionToSetParameters} @ bit,
+    crate::default::tokens::parameter::{CanSetParameters} @ bit,
 
-    crate::default::tokens::role::{CanUnregisterAnyRole},
+    crate::default::tokens::role::{CanUnregisterAnyRole} @ bit,
 
     crate::default::tokens::trigger::{CanExecuteUserTrigger},
     crate::default::tokens::trigger::{CanUnregisterUserTrigger},
     crate::default::tokens::trigger::{CanMintUserTrigger},
     crate::default::tokens::trigger::{CanBurnUserTrigger},
 
-    crate::default::tokens::executor::{CanUpgradeExecutor},
+    crate::default::tokens::executor::{CanUpgradeExecutor} @ bit,
+
+    crate::default::tokens::chain::{CanPauseChain} @ bit,
+}
+
+/// The wire representation of an [`AnyPermissionToken::Expiring`] grant: any other
+/// token's own `PermissionToken` encoding, paired with the block height at which the
+/// grant lapses. Kept as a plain, non-generic struct (rather than a generic `Expiring<T>`)
+/// so it can reuse this module's ordinary `token!`/[`declare_tokens!`] machinery instead
+/// of needing its own schema/conversion plumbing per wrapped type.
+///
+/// `expires_at_block == 0` means "never expires" — the implicit value for every token
+/// declared without this wrapper.
+token! {
+    pub struct ExpiringToken {
+        pub token: PermissionToken,
+        pub expires_at_block: u64,
+    }
+}
+
+impl AnyPermissionToken {
+    /// Collapses `tokens` into their semantically-distinct `PermissionToken`s, parsing
+    /// each one into an `AnyPermissionToken` and keeping only the first occurrence of
+    /// every distinct value (by the `PartialEq`/`Eq` `declare_tokens!` derives above), in
+    /// original order. A token that doesn't parse as one of this module's known tokens
+    /// (e.g. a custom, non-default one) is kept as-is and never treated as a duplicate of
+    /// anything else.
+    ///
+    /// This is the reusable half of the migration-time reconciliation
+    /// `role_permissions_unified` exercises, where `rose#wonderland#alice@wonderland` and
+    /// `rose##alice@wonderland` must collapse to one token despite differing verbatim. The
+    /// other half — a versioned `Executor` schema, an optional WASM `migrate` entrypoint,
+    /// and running this over every registered role/account atomically inside an upgrade
+    /// transaction — needs `Executor`/`Upgrade` and the host functions to iterate WSV
+    /// state, none of which live in this crate; that machinery belongs to iroha_core and
+    /// isn't part of this source tree.
+    pub(crate) fn dedup(tokens: Vec<PermissionToken>) -> Vec<PermissionToken> {
+        let mut seen: Vec<Self> = Vec::new();
+        tokens
+            .into_iter()
+            .filter(|token| match Self::try_from(token) {
+                Ok(parsed) => {
+                    if seen.contains(&parsed) {
+                        false
+                    } else {
+                        seen.push(parsed);
+                        true
+                    }
+                }
+                Err(_) => true,
+            })
+            .collect()
+    }
+
+    /// Resolves this token to its effective value as of `block_height`: an
+    /// [`AnyPermissionToken::Expiring`] grant that hasn't lapsed yet unwraps to its
+    /// inner token (recursively, in case of nested wrapping); one that has lapsed
+    /// resolves to `None`. Every other variant resolves to itself.
+    ///
+    /// Call sites that check "does this authority hold capability X" should resolve
+    /// through this before comparing, so an expired grant is treated as though it had
+    /// never been made.
+    pub(crate) fn resolve(&self, block_height: u64) -> Option<&AnyPermissionToken> {
+        match self {
+            Self::Expiring(inner, expires_at_block) => {
+                if *expires_at_block != 0 && block_height >= *expires_at_block {
+                    None
+                } else {
+                    inner.resolve(block_height)
+                }
+            }
+            other => Some(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod dedup_tests {
+    use super::*;
+
+    #[test]
+    fn dedup_collapses_differently_formatted_but_equal_tokens() {
+        // The long and shorthand forms of the same asset id (c.f. `role_permissions_unified`).
+        let verbose: PermissionToken = asset::CanTransferUserAsset {
+            asset_id: "rose#wonderland#alice@wonderland"
+                .parse()
+                .expect("valid asset id"),
+        }
+        .into();
+        let shorthand: PermissionToken = asset::CanTransferUserAsset {
+            asset_id: "rose##alice@wonderland".parse().expect("valid asset id"),
+        }
+        .into();
+
+        let deduped = AnyPermissionToken::dedup(alloc::vec![verbose, shorthand]);
+
+        assert_eq!(
+            deduped.len(),
+            1,
+            "the long and shorthand asset id forms name the same token"
+        );
+    }
+
+    #[test]
+    fn dedup_keeps_distinct_tokens() {
+        let rose: PermissionToken = asset::CanTransferUserAsset {
+            asset_id: "rose##alice@wonderland".parse().expect("valid asset id"),
+        }
+        .into();
+        let cabbage: PermissionToken = asset::CanTransferUserAsset {
+            asset_id: "cabbage##alice@wonderland".parse().expect("valid asset id"),
+        }
+        .into();
+
+        let deduped = AnyPermissionToken::dedup(alloc::vec![rose, cabbage]);
+
+        assert_eq!(deduped.len(), 2, "tokens for different assets aren't duplicates");
+    }
 }
 
 pub mod peer {
@@ -193,6 +854,22 @@ pub mod domain {
             pub domain_id: DomainId,
         }
     }
+
+    declare_ownership_handover! {
+        entity_kind: "domain",
+        id: DomainId::domain_id,
+        owner: permission::domain::Owner,
+        derive_conversions: permission::derive_conversions::domain::Owner,
+        key_value: {
+            get: |id: &DomainId, key| FindDomainKeyValueByIdAndKey::new(id.clone(), key)
+                .execute()
+                .ok()
+                .and_then(|value| String::try_from(value.into_inner()).ok()),
+            set: |id: &DomainId, key, value: String| SetKeyValue::domain(id.clone(), key, value).execute(),
+            remove: |id: &DomainId, key| RemoveKeyValue::domain(id.clone(), key).execute(),
+        },
+        tokens: CanProposeDomainOwnership, CanAcceptDomainOwnership,
+    }
 }
 
 pub mod account {
@@ -240,6 +917,22 @@ pub mod account {
             pub account_id: AccountId,
         }
     }
+
+    declare_ownership_handover! {
+        entity_kind: "account",
+        id: AccountId::account_id,
+        owner: permission::account::Owner,
+        derive_conversions: permission::derive_conversions::account::Owner,
+        key_value: {
+            get: |id: &AccountId, key| FindAccountKeyValueByIdAndKey::new(id.clone(), key)
+                .execute()
+                .ok()
+                .and_then(|value| String::try_from(value.into_inner()).ok()),
+            set: |id: &AccountId, key, value: String| SetKeyValue::account(id.clone(), key, value).execute(),
+            remove: |id: &AccountId, key| RemoveKeyValue::account(id.clone(), key).execute(),
+        },
+        tokens: CanProposeAccountOwnership, CanAcceptAccountOwnership,
+    }
 }
 
 pub mod asset_definition {
@@ -268,6 +961,24 @@ pub mod asset_definition {
             pub asset_definition_id: AssetDefinitionId,
         }
     }
+
+    declare_ownership_handover! {
+        entity_kind: "asset_definition",
+        id: AssetDefinitionId::asset_definition_id,
+        owner: permission::asset_definition::Owner,
+        derive_conversions: permission::derive_conversions::asset_definition::Owner,
+        key_value: {
+            get: |id: &AssetDefinitionId, key| FindAssetDefinitionKeyValueByIdAndKey::new(id.clone(), key)
+                .execute()
+                .ok()
+                .and_then(|value| String::try_from(value.into_inner()).ok()),
+            set: |id: &AssetDefinitionId, key, value: String| {
+                SetKeyValue::asset_definition(id.clone(), key, value).execute()
+            },
+            remove: |id: &AssetDefinitionId, key| RemoveKeyValue::asset_definition(id.clone(), key).execute(),
+        },
+        tokens: CanProposeAssetDefinitionOwnership, CanAcceptAssetDefinitionOwnership,
+    }
 }
 
 pub mod asset {
@@ -345,6 +1056,266 @@ pub mod asset {
         }
     }
 
+    token! {
+        #[derive(permission::derive_conversions::asset::Owner)]
+        pub struct CanTransferUserAssetUpToAmount {
+            pub asset_id: AssetId,
+            pub quota: u64,
+            pub valid_until_block: u64,
+        }
+    }
+
+    impl CanTransferUserAssetUpToAmount {
+        /// Whether this quota has lapsed as of `block_height`. `valid_until_block == 0`
+        /// means "never expires" — the same convention
+        /// [`AnyPermissionToken::resolve`] uses for `ExpiringToken`'s `expires_at_block`
+        /// — so a quota granted without an expiry isn't rejected the moment the chain
+        /// advances past block `0`.
+        fn is_expired(&self, block_height: u64) -> bool {
+            self.valid_until_block != 0 && self.valid_until_block < block_height
+        }
+    }
+
+    impl permission::ValidateGrantRevoke for CanTransferUserAssetUpToAmount {
+        fn validate_grant(&self, authority: &AccountId, block_height: u64) -> Result {
+            <Self as permission::asset::Owner>::validate_grant(self, authority, block_height)?;
+            if self.is_expired(block_height) {
+                return Err(ValidationFail::NotPermitted(
+                    "Cannot grant a transfer quota that has already expired".to_owned(),
+                ));
+            }
+            Ok(())
+        }
+
+        fn validate_revoke(&self, authority: &AccountId, block_height: u64) -> Result {
+            <Self as permission::asset::Owner>::validate_revoke(self, authority, block_height)
+        }
+    }
+
+    #[cfg(test)]
+    mod quota_expiry_tests {
+        use super::*;
+
+        fn token(valid_until_block: u64) -> CanTransferUserAssetUpToAmount {
+            CanTransferUserAssetUpToAmount {
+                asset_id: "rose#wonderland#alice@wonderland"
+                    .parse()
+                    .expect("valid asset id"),
+                quota: 100,
+                valid_until_block,
+            }
+        }
+
+        #[test]
+        fn zero_valid_until_block_never_expires() {
+            assert!(!token(0).is_expired(1));
+            assert!(!token(0).is_expired(u64::MAX));
+        }
+
+        #[test]
+        fn nonzero_valid_until_block_expires_once_passed() {
+            assert!(!token(10).is_expired(10));
+            assert!(token(10).is_expired(11));
+        }
+    }
+
+    /// Out-of-band remaining-quota bookkeeping for [`CanTransferUserAssetUpToAmount`].
+    ///
+    /// The token itself is an immutable grant record, so the mutable remaining balance is
+    /// tracked as a metadata entry on the grantee's account, keyed by this module's
+    /// [`quota_key`]. Meant to be called from `Transfer` validation before the transfer
+    /// is allowed to proceed — that visitor (`default::isi`'s `Transfer` handling) isn't
+    /// part of this crate's checkout, so [`validate_and_consume`] isn't reachable from
+    /// anywhere in this tree yet.
+    pub(crate) mod allowance {
+        use super::*;
+
+        /// The per-`(grantee, asset)` metadata key holding the remaining quota, as granted
+        /// by a [`CanTransferUserAssetUpToAmount`] token.
+        ///
+        /// `asset_id`'s `Display` contains `#`/`@`, which `Name` parsing rejects, so those
+        /// are replaced with `_` before parsing rather than passed through raw.
+        fn quota_key(asset_id: &AssetId) -> Name {
+            let asset_id = asset_id.to_string().replace(['#', '@'], "_");
+            format!("transfer_quota/{asset_id}")
+                .parse()
+                .expect("valid metadata key")
+        }
+
+        #[cfg(test)]
+        mod quota_key_tests {
+            use super::*;
+
+            #[test]
+            fn quota_key_parses_for_a_composite_asset_id() {
+                let asset_id: AssetId = "rose#wonderland#alice@wonderland"
+                    .parse()
+                    .expect("valid asset id");
+                // `AssetId`'s `Display` contains `#`/`@`; `quota_key` must not panic
+                // building a `Name` out of it.
+                let _ = quota_key(&asset_id);
+            }
+        }
+
+        /// Checks that the stored remaining quota under `key` still covers `amount`, then
+        /// returns the decrement/removal that should be applied — an error if no quota
+        /// entry exists, or if `amount` exceeds what remains.
+        ///
+        /// Split out from [`validate_and_consume`] so this decrement logic can be
+        /// unit-tested against an in-memory stand-in for the account metadata entry
+        /// `find`/`set`/`remove` address in the real call, the same way
+        /// `handover::propose`/`accept` are tested.
+        fn consume<F, S, R>(key: Name, amount: u64, find: F, set: S, remove: R) -> Result
+        where
+            F: FnOnce(Name) -> Option<u64>,
+            S: FnOnce(Name, u64) -> Result,
+            R: FnOnce(Name) -> Result,
+        {
+            let remaining = find(key.clone()).ok_or_else(|| {
+                ValidationFail::NotPermitted(
+                    "No transfer quota has been granted for this asset".to_owned(),
+                )
+            })?;
+
+            let Some(remaining) = remaining.checked_sub(amount) else {
+                return Err(ValidationFail::NotPermitted(
+                    "Transfer amount exceeds the remaining delegated quota".to_owned(),
+                ));
+            };
+
+            if remaining == 0 {
+                remove(key)
+            } else {
+                set(key, remaining)
+            }
+        }
+
+        /// Checks that `grantee` still has at least `amount` left of its transfer quota for
+        /// `asset_id`, then atomically decrements the stored remaining amount, removing the
+        /// entry entirely once it reaches zero.
+        ///
+        /// Returns an error if no quota entry exists, or if `amount` exceeds what remains.
+        pub(crate) fn validate_and_consume(
+            grantee: &AccountId,
+            asset_id: &AssetId,
+            amount: u64,
+        ) -> Result {
+            consume(
+                quota_key(asset_id),
+                amount,
+                |key| {
+                    FindAccountKeyValueByIdAndKey::new(grantee.clone(), key)
+                        .execute()
+                        .ok()
+                        .and_then(|value| u64::try_from(value.into_inner()).ok())
+                },
+                |key, remaining| SetKeyValue::account(grantee.clone(), key, remaining).execute(),
+                |key| RemoveKeyValue::account(grantee.clone(), key).execute(),
+            )
+        }
+
+        #[cfg(test)]
+        mod consume_tests {
+            use core::cell::RefCell;
+
+            use super::*;
+
+            fn key() -> Name {
+                "transfer_quota/test".parse().expect("valid metadata key")
+            }
+
+            fn store(remaining: u64) -> RefCell<Option<(Name, u64)>> {
+                RefCell::new(Some((key(), remaining)))
+            }
+
+            fn find(store: &RefCell<Option<(Name, u64)>>, key: Name) -> Option<u64> {
+                store
+                    .borrow()
+                    .as_ref()
+                    .filter(|(k, _)| *k == key)
+                    .map(|(_, remaining)| *remaining)
+            }
+
+            #[test]
+            fn decrements_the_remaining_quota() {
+                let store = store(100);
+
+                consume(
+                    key(),
+                    40,
+                    |key| find(&store, key),
+                    |key, remaining| {
+                        *store.borrow_mut() = Some((key, remaining));
+                        Ok(())
+                    },
+                    |_| unreachable!("60 remaining shouldn't be removed"),
+                )
+                .expect("40 is within the remaining 100");
+
+                assert_eq!(store.borrow().as_ref().map(|(_, r)| *r), Some(60));
+            }
+
+            #[test]
+            fn removes_the_entry_once_it_reaches_zero() {
+                let store = store(40);
+
+                consume(
+                    key(),
+                    40,
+                    |key| find(&store, key),
+                    |_, _| unreachable!("an exact decrement to zero should remove, not set"),
+                    |_| {
+                        *store.borrow_mut() = None;
+                        Ok(())
+                    },
+                )
+                .expect("40 exactly exhausts the remaining 40");
+
+                assert!(
+                    store.borrow().is_none(),
+                    "a quota fully consumed should be removed, not left at zero"
+                );
+            }
+
+            #[test]
+            fn rejects_an_amount_exceeding_the_remaining_quota() {
+                let store = store(10);
+
+                let err = consume(
+                    key(),
+                    11,
+                    |key| find(&store, key),
+                    |_, _| unreachable!("an over-large amount should be rejected before writing"),
+                    |_| unreachable!("an over-large amount should be rejected before writing"),
+                )
+                .expect_err("11 exceeds the remaining 10");
+
+                assert!(matches!(err, ValidationFail::NotPermitted(_)));
+                assert_eq!(
+                    store.borrow().as_ref().map(|(_, r)| *r),
+                    Some(10),
+                    "a rejected consume must leave the remaining quota unchanged"
+                );
+            }
+
+            #[test]
+            fn rejects_when_no_quota_has_been_granted() {
+                let store: RefCell<Option<(Name, u64)>> = RefCell::new(None);
+
+                let err = consume(
+                    key(),
+                    1,
+                    |key| find(&store, key),
+                    |_, _| unreachable!("no quota entry means nothing should be written"),
+                    |_| unreachable!("no quota entry means nothing should be written"),
+                )
+                .expect_err("no quota entry exists for this key");
+
+                assert!(matches!(err, ValidationFail::NotPermitted(_)));
+            }
+        }
+    }
+
     token! {
         #[derive(ValidateGrantRevoke, permission::derive_conversions::asset::Owner)]
         #[validate(permission::asset::Owner)]
@@ -458,6 +1429,113 @@ pub mod role {
         #[validate(permission::OnlyGenesis)]
         pub struct CanUnregisterAnyRole;
     }
+
+    declare_roles! {
+        // Bundles every `domain::*` token that takes only a `DomainId` (each variant
+        // instantiated with that id; the ownership-handover pair is not included);
+        // reassigning it is left to whichever per-token owner check already applies.
+        DomainAdmin: OwnerRolePolicy::None,
+        // Bundles the executor-upgrade and parameter-administration tokens; these are
+        // sensitive enough that, once granted, only genesis may reassign them.
+        CoreOperator: OwnerRolePolicy::Fixed,
+    }
+
+    impl AnyRole {
+        /// Builds the [`AnyRole::DomainAdmin`] bundle for `domain_id`: every
+        /// `domain::*` token parameterized by that id alone (`CanProposeDomainOwnership`
+        /// and `CanAcceptDomainOwnership` are not bundled), so granting or revoking the
+        /// role validates (and, once wired into a `Grant<Role>`/`Revoke<Role>`
+        /// instruction visitor, applies) the whole bundle atomically instead of one
+        /// token at a time.
+        pub(crate) fn domain_admin(domain_id: DomainId) -> Self {
+            Self::DomainAdmin(alloc::vec![
+                AnyPermissionToken::CanUnregisterDomain(domain::CanUnregisterDomain {
+                    domain_id: domain_id.clone(),
+                }),
+                AnyPermissionToken::CanSetKeyValueInDomain(domain::CanSetKeyValueInDomain {
+                    domain_id: domain_id.clone(),
+                }),
+                AnyPermissionToken::CanRemoveKeyValueInDomain(domain::CanRemoveKeyValueInDomain {
+                    domain_id: domain_id.clone(),
+                }),
+                AnyPermissionToken::CanRegisterAccountInDomain(
+                    domain::CanRegisterAccountInDomain {
+                        domain_id: domain_id.clone(),
+                    },
+                ),
+                AnyPermissionToken::CanRegisterAssetDefinitionInDomain(
+                    domain::CanRegisterAssetDefinitionInDomain { domain_id },
+                ),
+            ])
+        }
+
+        /// Builds the [`AnyRole::CoreOperator`] bundle: the executor-upgrade and
+        /// parameter-administration tokens, none of which carry an id of their own.
+        pub(crate) fn core_operator() -> Self {
+            Self::CoreOperator(alloc::vec![
+                AnyPermissionToken::CanUpgradeExecutor(executor::CanUpgradeExecutor),
+                AnyPermissionToken::CanGrantPermissionToCreateParameters(
+                    parameter::CanGrantPermissionToCreateParameters,
+                ),
+                AnyPermissionToken::CanRevokePermissionToCreateParameters(
+                    parameter::CanRevokePermissionToCreateParameters,
+                ),
+                AnyPermissionToken::CanCreateParameters(parameter::CanCreateParameters),
+                AnyPermissionToken::CanGrantPermissionToSetParameters(
+                    parameter::CanGrantPermissionToSetParameters,
+                ),
+                AnyPermissionToken::CanRevokePermissionToSetParameters(
+                    parameter::CanRevokePermissionToSetParameters,
+                ),
+                AnyPermissionToken::CanSetParameters(parameter::CanSetParameters),
+            ])
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn domain_id() -> DomainId {
+            "wonderland".parse().expect("valid domain id")
+        }
+
+        fn genesis() -> AccountId {
+            "genesis@genesis".parse().expect("valid account id")
+        }
+
+        #[test]
+        fn domain_admin_bundles_every_domain_token_for_the_given_id() {
+            // Checked by construction rather than by calling `validate_grant`: each
+            // bundled token's `domain::Owner` check queries the WSV for the domain's
+            // current owner, which needs a live host this crate's unit tests don't have.
+            let AnyRole::DomainAdmin(tokens) = AnyRole::domain_admin(domain_id()) else {
+                unreachable!("domain_admin always builds the DomainAdmin variant");
+            };
+            assert_eq!(
+                tokens.len(),
+                5,
+                "one entry per domain::* token that takes only a domain id"
+            );
+            assert!(tokens
+                .iter()
+                .all(|token| matches!(token, AnyPermissionToken::CanUnregisterDomain(t) if t.domain_id == domain_id())
+                    || matches!(token, AnyPermissionToken::CanSetKeyValueInDomain(t) if t.domain_id == domain_id())
+                    || matches!(token, AnyPermissionToken::CanRemoveKeyValueInDomain(t) if t.domain_id == domain_id())
+                    || matches!(token, AnyPermissionToken::CanRegisterAccountInDomain(t) if t.domain_id == domain_id())
+                    || matches!(token, AnyPermissionToken::CanRegisterAssetDefinitionInDomain(t) if t.domain_id == domain_id())));
+        }
+
+        #[test]
+        fn core_operator_bundle_is_fixed_even_for_genesis() {
+            use permission::ValidateGrantRevoke as _;
+
+            let role = AnyRole::core_operator();
+            // `OwnerRolePolicy::Fixed` rejects every reassignment, genesis included.
+            role.validate_grant(&genesis(), 0)
+                .expect_err("a `Fixed` role can never be (re)granted, not even by genesis");
+        }
+    }
 }
 
 pub mod trigger {
@@ -524,3 +1602,28 @@ pub mod executor {
         pub struct CanUpgradeExecutor;
     }
 }
+
+pub mod chain {
+    use super::*;
+
+    /// Permission token for pausing and unpausing the chain. Root-gated like
+    /// [`executor::CanUpgradeExecutor`], since it is a whole-chain emergency brake rather
+    /// than a per-entity capability.
+    ///
+    /// This token is only the permission check. The `Pause`/`Unpause`
+    /// instructions themselves, the WSV `paused` flag they flip, and the executor-level
+    /// rejection of every other instruction while it's set all need `iroha_data_model`'s
+    /// `InstructionBox`/`isi` and the WSV mutation/query surface to iterate and gate
+    /// incoming instructions — none of which live in this crate; that machinery belongs
+    /// to iroha_core and isn't part of this source tree, the same gap
+    /// [`AnyPermissionToken::dedup`]'s doc comment notes for the migration mechanism. So
+    /// unlike the rest of this module's tokens, `CanPauseChain` currently has nothing to
+    /// gate: there's no call site, and no test analogous to
+    /// `role_with_invalid_permissions_is_not_accepted` exists because there's no pause
+    /// rejection path in this tree to exercise.
+    token! {
+        #[derive(Copy, ValidateGrantRevoke)]
+        #[validate(permission::OnlyGenesis)]
+        pub struct CanPauseChain;
+    }
+}
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf, str::FromStr};
 
 use clap::{Parser, Subcommand};
 use iroha_config::parameters::defaults::chain_wide::{
@@ -8,7 +8,7 @@ use iroha_config::parameters::defaults::chain_wide::{
 };
 use iroha_data_model::{
     metadata::Limits,
-    parameter::{default::*, ParametersBuilder},
+    parameter::{default::*, ParameterId, ParametersBuilder},
     prelude::AssetId,
 };
 use iroha_genesis::{executor_state, RawGenesisBlockBuilder, RawGenesisBlockFile};
@@ -21,10 +21,157 @@ pub struct Args {
     /// Specifies the `executor_file` <PATH> that will be inserted into the genesis JSON as-is.
     #[clap(long, value_name = "PATH")]
     executor_path_in_genesis: PathBuf,
+    /// Overrides a chain-wide parameter, e.g. `--set-parameter WASMFuelLimit=42000000`.
+    /// May be specified multiple times. Unknown keys or values out of range are rejected.
+    #[clap(long, value_name = "KEY=VALUE")]
+    set_parameter: Vec<ParameterOverride>,
+    /// Path to a JSON file of `{ "KEY": VALUE, ... }` parameter overrides, applied before
+    /// `--set-parameter`, so a repeated `--set-parameter` wins on conflicts.
+    #[clap(long, value_name = "PATH")]
+    parameters_file: Option<PathBuf>,
     #[clap(subcommand)]
     mode: Option<Mode>,
 }
 
+/// Every chain-wide parameter this generator knows how to set, i.e. every one it ever
+/// passes to a [`ParametersBuilder`] in [`generate_default`]/[`generate_synthetic`]. An
+/// override naming anything outside this set is rejected in [`parse_known_parameter_id`]
+/// rather than left for `ParametersBuilder::add_parameter` to notice, since that method
+/// also accepts brand-new parameter ids for genesis to declare and so can't tell
+/// "unknown" apart from "new" on its own.
+fn known_parameter_ids() -> [ParameterId; 13] {
+    [
+        MAX_TRANSACTIONS_IN_BLOCK,
+        BLOCK_TIME,
+        COMMIT_TIME_LIMIT,
+        TRANSACTION_LIMITS,
+        WSV_ASSET_METADATA_LIMITS,
+        WSV_ASSET_DEFINITION_METADATA_LIMITS,
+        WSV_ACCOUNT_METADATA_LIMITS,
+        WSV_DOMAIN_METADATA_LIMITS,
+        WSV_IDENT_LENGTH_LIMITS,
+        EXECUTOR_FUEL_LIMIT,
+        EXECUTOR_MAX_MEMORY,
+        WASM_FUEL_LIMIT,
+        WASM_MAX_MEMORY,
+    ]
+}
+
+/// Parses `key` as a [`ParameterId`] and checks it against [`known_parameter_ids`],
+/// failing loudly instead of silently accepting a lexically-valid but nonexistent
+/// parameter name such as `BogusKey`.
+fn parse_known_parameter_id(key: &str) -> color_eyre::Result<ParameterId> {
+    let id: ParameterId = key
+        .parse()
+        .wrap_err_with(|| format!("`{key}` is not a known chain parameter"))?;
+    if known_parameter_ids()
+        .iter()
+        .any(|known| known.to_string() == id.to_string())
+    {
+        Ok(id)
+    } else {
+        Err(eyre!("`{key}` is not a known chain parameter"))
+    }
+}
+
+/// A single `KEY=VALUE` chain-parameter override taken from the CLI or an override file.
+#[derive(Debug, Clone)]
+struct ParameterOverride {
+    id: ParameterId,
+    value: Numeric,
+}
+
+impl FromStr for ParameterOverride {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| eyre!("expected `KEY=VALUE`, got `{s}`"))?;
+        Ok(Self {
+            id: parse_known_parameter_id(key)?,
+            value: value
+                .parse()
+                .wrap_err_with(|| format!("`{value}` is not a valid numeric parameter value"))?,
+        })
+    }
+}
+
+/// Parameter overrides loaded from a JSON file, as `{ "KEY": value, ... }`.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct ParameterOverridesFile(BTreeMap<String, Numeric>);
+
+impl ParameterOverridesFile {
+    fn into_overrides(self) -> color_eyre::Result<Vec<ParameterOverride>> {
+        self.0
+            .into_iter()
+            .map(|(key, value)| {
+                Ok(ParameterOverride {
+                    id: parse_known_parameter_id(&key)?,
+                    value,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The full set of chain-wide parameters `generate_default` seeds, each already set to
+/// its shipped default. Factored out so the unit tests can exercise
+/// [`apply_parameter_overrides`] against the same already-populated builder the real
+/// `--set-parameter`/`--parameters-file` call site does, instead of only ever against a
+/// fresh, empty one.
+fn default_chain_parameters() -> color_eyre::Result<ParametersBuilder> {
+    Ok(ParametersBuilder::new()
+        .add_parameter(
+            MAX_TRANSACTIONS_IN_BLOCK,
+            Numeric::new(DEFAULT_MAX_TXS.get().into(), 0),
+        )?
+        .add_parameter(BLOCK_TIME, Numeric::new(DEFAULT_BLOCK_TIME.as_millis(), 0))?
+        .add_parameter(
+            COMMIT_TIME_LIMIT,
+            Numeric::new(DEFAULT_COMMIT_TIME.as_millis(), 0),
+        )?
+        .add_parameter(TRANSACTION_LIMITS, DEFAULT_TRANSACTION_LIMITS)?
+        .add_parameter(WSV_ASSET_METADATA_LIMITS, DEFAULT_METADATA_LIMITS)?
+        .add_parameter(
+            WSV_ASSET_DEFINITION_METADATA_LIMITS,
+            DEFAULT_METADATA_LIMITS,
+        )?
+        .add_parameter(WSV_ACCOUNT_METADATA_LIMITS, DEFAULT_METADATA_LIMITS)?
+        .add_parameter(WSV_DOMAIN_METADATA_LIMITS, DEFAULT_METADATA_LIMITS)?
+        .add_parameter(WSV_IDENT_LENGTH_LIMITS, DEFAULT_IDENT_LENGTH_LIMITS)?
+        .add_parameter(
+            EXECUTOR_FUEL_LIMIT,
+            Numeric::new(DEFAULT_WASM_FUEL_LIMIT.into(), 0),
+        )?
+        .add_parameter(
+            EXECUTOR_MAX_MEMORY,
+            Numeric::new(DEFAULT_WASM_MAX_MEMORY_BYTES.into(), 0),
+        )?
+        .add_parameter(
+            WASM_FUEL_LIMIT,
+            Numeric::new(DEFAULT_WASM_FUEL_LIMIT.into(), 0),
+        )?
+        .add_parameter(
+            WASM_MAX_MEMORY,
+            Numeric::new(DEFAULT_WASM_MAX_MEMORY_BYTES.into(), 0),
+        )?)
+}
+
+/// Applies `overrides` on top of `builder`'s already-set defaults, failing loudly on
+/// unknown keys or numerics that don't pass `ParametersBuilder`'s validation.
+fn apply_parameter_overrides(
+    mut builder: ParametersBuilder,
+    overrides: impl IntoIterator<Item = ParameterOverride>,
+) -> color_eyre::Result<ParametersBuilder> {
+    for ParameterOverride { id, value } in overrides {
+        builder = builder
+            .add_parameter(id.clone(), value)
+            .wrap_err_with(|| format!("failed to override parameter `{id}`"))?;
+    }
+    Ok(builder)
+}
+
 #[derive(Subcommand, Debug, Clone, Default)]
 pub enum Mode {
     /// Generate default genesis
@@ -54,17 +201,36 @@ impl<T: Write> RunArgs<T> for Args {
     fn run(self, writer: &mut BufWriter<T>) -> Outcome {
         let Self {
             executor_path_in_genesis,
+            set_parameter,
+            parameters_file,
             mode,
         } = self;
 
+        let mut overrides = Vec::new();
+        if let Some(path) = parameters_file {
+            let file = std::fs::File::open(&path)
+                .wrap_err_with(|| format!("failed to open parameter overrides file {path:?}"))?;
+            let from_file: ParameterOverridesFile = serde_json::from_reader(file)
+                .wrap_err_with(|| format!("failed to parse parameter overrides file {path:?}"))?;
+            overrides.extend(from_file.into_overrides()?);
+        }
+        // CLI `--set-parameter` flags are applied last, so they win over the override file.
+        overrides.extend(set_parameter);
+
         let builder = RawGenesisBlockBuilder::default().executor_file(executor_path_in_genesis);
         let genesis = match mode.unwrap_or_default() {
-            Mode::Default => generate_default(builder),
+            Mode::Default => generate_default(builder, overrides),
             Mode::Synthetic {
                 domains,
                 accounts_per_domain,
                 assets_per_domain,
-            } => generate_synthetic(builder, domains, accounts_per_domain, assets_per_domain),
+            } => generate_synthetic(
+                builder,
+                domains,
+                accounts_per_domain,
+                assets_per_domain,
+                overrides,
+            ),
         }?;
         writeln!(writer, "{}", serde_json::to_string_pretty(&genesis)?)
             .wrap_err("failed to write serialized genesis to the buffer")
@@ -74,6 +240,7 @@ impl<T: Write> RunArgs<T> for Args {
 #[allow(clippy::too_many_lines)]
 pub fn generate_default(
     builder: RawGenesisBlockBuilder<executor_state::SetPath>,
+    parameter_overrides: Vec<ParameterOverride>,
 ) -> color_eyre::Result<RawGenesisBlockFile> {
     let mut meta = Metadata::new();
     meta.insert_with_limits("key".parse()?, "value".to_owned(), Limits::new(1024, 1024))?;
@@ -131,42 +298,9 @@ pub fn generate_default(
     )
     .into();
 
-    let parameter_defaults = ParametersBuilder::new()
-        .add_parameter(
-            MAX_TRANSACTIONS_IN_BLOCK,
-            Numeric::new(DEFAULT_MAX_TXS.get().into(), 0),
-        )?
-        .add_parameter(BLOCK_TIME, Numeric::new(DEFAULT_BLOCK_TIME.as_millis(), 0))?
-        .add_parameter(
-            COMMIT_TIME_LIMIT,
-            Numeric::new(DEFAULT_COMMIT_TIME.as_millis(), 0),
-        )?
-        .add_parameter(TRANSACTION_LIMITS, DEFAULT_TRANSACTION_LIMITS)?
-        .add_parameter(WSV_ASSET_METADATA_LIMITS, DEFAULT_METADATA_LIMITS)?
-        .add_parameter(
-            WSV_ASSET_DEFINITION_METADATA_LIMITS,
-            DEFAULT_METADATA_LIMITS,
-        )?
-        .add_parameter(WSV_ACCOUNT_METADATA_LIMITS, DEFAULT_METADATA_LIMITS)?
-        .add_parameter(WSV_DOMAIN_METADATA_LIMITS, DEFAULT_METADATA_LIMITS)?
-        .add_parameter(WSV_IDENT_LENGTH_LIMITS, DEFAULT_IDENT_LENGTH_LIMITS)?
-        .add_parameter(
-            EXECUTOR_FUEL_LIMIT,
-            Numeric::new(DEFAULT_WASM_FUEL_LIMIT.into(), 0),
-        )?
-        .add_parameter(
-            EXECUTOR_MAX_MEMORY,
-            Numeric::new(DEFAULT_WASM_MAX_MEMORY_BYTES.into(), 0),
-        )?
-        .add_parameter(
-            WASM_FUEL_LIMIT,
-            Numeric::new(DEFAULT_WASM_FUEL_LIMIT.into(), 0),
-        )?
-        .add_parameter(
-            WASM_MAX_MEMORY,
-            Numeric::new(DEFAULT_WASM_MAX_MEMORY_BYTES.into(), 0),
-        )?
-        .into_create_parameters();
+    let parameter_defaults =
+        apply_parameter_overrides(default_chain_parameters()?, parameter_overrides)?
+            .into_create_parameters();
 
     let first_tx = genesis
         .first_transaction_mut()
@@ -192,6 +326,7 @@ fn generate_synthetic(
     domains: u64,
     accounts_per_domain: u64,
     assets_per_domain: u64,
+    parameter_overrides: Vec<ParameterOverride>,
 ) -> color_eyre::Result<RawGenesisBlockFile> {
     // Add default `Domain` and `Account` to still be able to query
     let mut builder = builder
@@ -240,5 +375,79 @@ fn generate_synthetic(
         }
     }
 
+    let parameter_defaults = apply_parameter_overrides(
+        ParametersBuilder::new()
+            .add_parameter(
+                WASM_FUEL_LIMIT,
+                Numeric::new(DEFAULT_WASM_FUEL_LIMIT.into(), 0),
+            )?
+            .add_parameter(
+                WASM_MAX_MEMORY,
+                Numeric::new(DEFAULT_WASM_MAX_MEMORY_BYTES.into(), 0),
+            )?,
+        parameter_overrides,
+    )?
+    .into_create_parameters();
+    for isi in parameter_defaults {
+        first_transaction.append_instruction(isi);
+    }
+
     Ok(genesis)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_parameter_rejects_unknown_key() {
+        let err = "BogusKey=1"
+            .parse::<ParameterOverride>()
+            .expect_err("`BogusKey` names no chain parameter");
+        assert!(err.to_string().contains("not a known chain parameter"));
+    }
+
+    #[test]
+    fn set_parameter_accepts_known_key() {
+        let input = format!("{WASM_FUEL_LIMIT}=42000000");
+        input
+            .parse::<ParameterOverride>()
+            .expect("a declared default parameter should be accepted");
+    }
+
+    #[test]
+    fn set_parameter_rejects_malformed_numeric() {
+        let input = format!("{WASM_FUEL_LIMIT}=not-a-number");
+        let err = input
+            .parse::<ParameterOverride>()
+            .expect_err("`not-a-number` is not a `Numeric`");
+        assert!(err.to_string().contains("not a valid numeric parameter value"));
+    }
+
+    #[test]
+    fn set_parameter_rejects_out_of_range_numeric() {
+        // `MAX_TRANSACTIONS_IN_BLOCK` is backed by a `u32`; a value far outside that range
+        // must be rejected by `ParametersBuilder::add_parameter`, not silently truncated.
+        let input = format!("{MAX_TRANSACTIONS_IN_BLOCK}={}", u128::MAX);
+        let over = input
+            .parse::<ParameterOverride>()
+            .expect("lexically valid KEY=VALUE pair");
+        apply_parameter_overrides(ParametersBuilder::new(), [over])
+            .expect_err("out-of-range numeric must be rejected, not stored as-is");
+    }
+
+    #[test]
+    fn set_parameter_overrides_a_key_already_set_to_its_default() {
+        // `generate_default`'s real call site applies overrides on top of
+        // `default_chain_parameters()`, which already has every known parameter set — not
+        // a fresh, empty builder. `add_parameter` must accept replacing an existing key.
+        let over = format!("{WASM_FUEL_LIMIT}=42000000")
+            .parse::<ParameterOverride>()
+            .expect("a declared default parameter should be accepted");
+        apply_parameter_overrides(
+            default_chain_parameters().expect("every default parameter is valid"),
+            [over],
+        )
+        .expect("overriding a key the builder already set to its default must succeed");
+    }
+}